@@ -54,19 +54,5 @@ fn get_fraction(prompt: &str) -> Result<Fraction, Box<dyn std::error::Error>> {
 
     let mut input = String::new();
     io::stdin().read_line(&mut input)?;
-    let input = input.trim();
-
-    if input.contains('/') {
-        let parts: Vec<&str> = input.split('/').collect();
-        if parts.len() != 2 {
-            return Err("Invalid format".into());
-        }
-
-        let num: i64 = parts[0].parse()?;
-        let den: i64 = parts[1].parse()?;
-        Ok(Fraction::new(num, den)?)
-    } else {
-        let num: i64 = input.parse()?;
-        Ok(Fraction::from(num))
-    }
+    Ok(input.parse::<Fraction>()?)
 }
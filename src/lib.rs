@@ -20,8 +20,13 @@
 //! ```
 
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::fmt;
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::hash::{Hash, Hasher};
+use std::iter::{Product, Sum};
+use std::num::{IntErrorKind, ParseIntError};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use std::str::FromStr;
 
 /// Error types for fraction operations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -30,6 +35,16 @@ pub enum FractionError {
     ZeroDenominator,
     /// Division by zero fraction
     DivisionByZero,
+    /// The fraction does not represent a whole number
+    NotAnInteger,
+    /// The result does not fit in the underlying integer type
+    Overflow,
+    /// Attempted to convert a NaN or infinite floating-point value
+    NotFinite,
+    /// An argument was outside the range accepted by the operation
+    InvalidArgument,
+    /// The input string is not a valid fraction
+    ParseError,
 }
 
 impl fmt::Display for FractionError {
@@ -37,16 +52,50 @@ impl fmt::Display for FractionError {
         match self {
             FractionError::ZeroDenominator => write!(f, "denominator cannot be zero"),
             FractionError::DivisionByZero => write!(f, "cannot divide by zero"),
+            FractionError::NotAnInteger => write!(f, "fraction is not an integer"),
+            FractionError::Overflow => write!(f, "arithmetic overflow"),
+            FractionError::NotFinite => write!(f, "value is not finite"),
+            FractionError::InvalidArgument => write!(f, "invalid argument"),
+            FractionError::ParseError => write!(f, "invalid fraction syntax"),
         }
     }
 }
 
 impl std::error::Error for FractionError {}
 
+/// Strategies for rounding a fraction to an integer.
+///
+/// The `Half*` modes round to the nearest integer and differ only in how they
+/// break an exact tie between two integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round towards negative infinity
+    Floor,
+    /// Round towards positive infinity
+    Ceiling,
+    /// Round towards zero, discarding the fractional part
+    TowardZero,
+    /// Round away from zero
+    AwayFromZero,
+    /// Round to nearest, ties towards positive infinity
+    HalfUp,
+    /// Round to nearest, ties towards negative infinity
+    HalfDown,
+    /// Round to nearest, ties towards zero
+    HalfTowardZero,
+    /// Round to nearest, ties away from zero
+    HalfAwayFromZero,
+    /// Round to nearest, ties to the even integer (bankers' rounding)
+    HalfToEven,
+    /// Round to nearest, ties to the odd integer
+    HalfToOdd,
+}
+
 /// A fraction with numerator and denominator.
 ///
-/// Fractions are automatically reduced to lowest terms when displayed.
-/// The sign is always kept in the numerator.
+/// Fractions built with [`Fraction::new`] are stored in lowest terms; use
+/// [`Fraction::new_unreduced`] to keep the raw pair. The sign is always kept
+/// in the numerator.
 #[derive(Debug, Clone, Copy)]
 pub struct Fraction {
     numerator: i64,
@@ -54,30 +103,72 @@ pub struct Fraction {
 }
 
 impl Fraction {
-    /// Creates a new fraction.
+    /// The fraction `0/1`.
+    pub const ZERO: Self = Self {
+        numerator: 0,
+        denominator: 1,
+    };
+
+    /// The fraction `1/1`.
+    pub const ONE: Self = Self {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    /// Creates a new fraction in lowest terms.
+    ///
+    /// The fraction is reduced and its sign is moved to the numerator.
     ///
     /// # Errors
     ///
-    /// Returns `FractionError::ZeroDenominator` if denominator is zero.
+    /// Returns `FractionError::ZeroDenominator` if denominator is zero, or
+    /// `FractionError::Overflow` if the reduced numerator does not fit in an
+    /// `i64` once the sign is moved (only `i64::MIN / -1`).
     ///
     /// # Examples
     ///
     /// ```
     /// use fractions::Fraction;
     ///
-    /// let frac = Fraction::new(3, 4)?;
-    /// assert_eq!(frac.numerator(), 3);
-    /// assert_eq!(frac.denominator(), 4);
+    /// let frac = Fraction::new(12, -8)?;
+    /// assert_eq!(frac.numerator(), -3);
+    /// assert_eq!(frac.denominator(), 2);
     /// # Ok::<(), fractions::FractionError>(())
     /// ```
     pub fn new(numerator: i64, denominator: i64) -> Result<Self, FractionError> {
+        Self::from_i128(numerator as i128, denominator as i128)
+    }
+
+    /// Creates a new fraction without reducing it.
+    ///
+    /// Only the sign is normalized to the numerator.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::ZeroDenominator` if denominator is zero, or
+    /// `FractionError::Overflow` if moving the sign would negate `i64::MIN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let frac = Fraction::new_unreduced(12, 8)?;
+    /// assert_eq!(frac.numerator(), 12);
+    /// assert_eq!(frac.denominator(), 8);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn new_unreduced(numerator: i64, denominator: i64) -> Result<Self, FractionError> {
         if denominator == 0 {
             return Err(FractionError::ZeroDenominator);
         }
 
         // Normalize sign to numerator
         let (num, den) = if denominator < 0 {
-            (-numerator, -denominator)
+            (
+                numerator.checked_neg().ok_or(FractionError::Overflow)?,
+                denominator.checked_neg().ok_or(FractionError::Overflow)?,
+            )
         } else {
             (numerator, denominator)
         };
@@ -88,6 +179,40 @@ impl Fraction {
         })
     }
 
+    /// Creates a fraction in a `const` context, e.g.
+    /// `const HALF: Fraction = Fraction::new_const(1, 2);`.
+    ///
+    /// The sign is moved to the numerator but the fraction is not reduced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero, which is a compile error when
+    /// evaluated in a `const` context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// const QUARTER: Fraction = Fraction::new_const(-1, -4);
+    /// assert_eq!(QUARTER, Fraction::new(1, 4)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub const fn new_const(numerator: i64, denominator: i64) -> Self {
+        assert!(denominator != 0, "denominator cannot be zero");
+        if denominator < 0 {
+            Self {
+                numerator: -numerator,
+                denominator: -denominator,
+            }
+        } else {
+            Self {
+                numerator,
+                denominator,
+            }
+        }
+    }
+
     /// Creates a fraction representing a whole number.
     pub fn from_integer(n: i64) -> Self {
         Self {
@@ -96,358 +221,6470 @@ impl Fraction {
         }
     }
 
-    /// Returns the numerator.
-    pub fn numerator(&self) -> i64 {
-        self.numerator
+    /// Returns the progress `current / total` clamped into `[0, 1]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::ZeroDenominator` if `total` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::progress(3, 12)?, Fraction::new(1, 4)?);
+    /// assert_eq!(Fraction::progress(15, 12)?, Fraction::from_integer(1));
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn progress(current: i64, total: i64) -> Result<Self, FractionError> {
+        let ratio = Self::new(current, total)?;
+        Ok(ratio.clamp(Self::from_integer(0), Self::from_integer(1)))
     }
 
-    /// Returns the denominator.
-    pub fn denominator(&self) -> i64 {
-        self.denominator
-    }
+    /// Creates a fraction holding the exact value of a floating-point number.
+    ///
+    /// Every finite `f64` is a dyadic rational, so no rounding takes place.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::NotFinite` for NaN or infinite values, or
+    /// `FractionError::Overflow` if the exact value does not fit in `i64` terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let frac = Fraction::from_f64_exact(0.375)?;
+    /// assert_eq!(frac, Fraction::new(3, 8)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn from_f64_exact(value: f64) -> Result<Self, FractionError> {
+        if !value.is_finite() {
+            return Err(FractionError::NotFinite);
+        }
 
-    /// Converts the fraction to a floating-point number.
-    pub fn to_f64(&self) -> f64 {
-        self.numerator as f64 / self.denominator as f64
+        let (negative, mantissa, exponent) = decode_f64(value);
+        Self::from_binary_parts(negative, mantissa, exponent)
     }
 
-    /// Returns the absolute value of the fraction.
-    pub fn abs(&self) -> Self {
-        Self {
-            numerator: self.numerator.abs(),
-            denominator: self.denominator,
+    /// Creates the fraction `value / 2^frac_bits` from a Q-format fixed-point number, reduced.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::Overflow` if the reduced denominator does not
+    /// fit in an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::from_dyadic_parts(98304, 16)?, Fraction::new(3, 2)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn from_dyadic_parts(value: i64, frac_bits: u32) -> Result<Self, FractionError> {
+        if value == 0 {
+            return Ok(Self::ZERO);
         }
-    }
 
-    /// Checks if the fraction is positive.
-    pub fn is_positive(&self) -> bool {
-        self.numerator > 0
+        // Cancel common factors of two first so large `frac_bits` stay in range
+        let shift = frac_bits.min(value.trailing_zeros());
+        let bits = frac_bits - shift;
+        if bits > 63 {
+            return Err(FractionError::Overflow);
+        }
+        Self::from_i128((value >> shift) as i128, 1i128 << bits)
     }
 
-    /// Checks if the fraction is negative.
-    pub fn is_negative(&self) -> bool {
-        self.numerator < 0
-    }
+    /// Creates a fraction holding the exact value of a 32-bit floating-point number.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::NotFinite` for NaN or infinite values, or
+    /// `FractionError::Overflow` if the exact value does not fit in `i64` terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::try_from_f32_exact(0.5)?, Fraction::new(1, 2)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn try_from_f32_exact(x: f32) -> Result<Self, FractionError> {
+        if !x.is_finite() {
+            return Err(FractionError::NotFinite);
+        }
 
-    /// Checks if the fraction is zero.
-    pub fn is_zero(&self) -> bool {
-        self.numerator == 0
+        let bits = x.to_bits();
+        let negative = bits >> 31 == 1;
+        let biased_exponent = ((bits >> 23) & 0xff) as i32;
+        let fraction = (bits & ((1 << 23) - 1)) as u64;
+
+        if biased_exponent == 0 {
+            // Subnormal: no implicit leading bit
+            Self::from_binary_parts(negative, fraction, -149)
+        } else {
+            Self::from_binary_parts(negative, fraction | (1 << 23), biased_exponent - 150)
+        }
     }
 
-    /// Returns the reciprocal of the fraction.
+    /// Returns the fraction closest to `num / den` whose denominator is at most `max_den`.
+    ///
+    /// The ratio is computed in floating point first, then approximated with
+    /// continued fractions, which makes this suitable for measured data.
     ///
     /// # Errors
     ///
-    /// Returns `FractionError::DivisionByZero` if the numerator is zero.
+    /// Returns `FractionError::ZeroDenominator` if `den` is zero,
+    /// `FractionError::NotFinite` if either input is NaN or infinite,
+    /// `FractionError::InvalidArgument` if `max_den` is less than one, or
+    /// `FractionError::Overflow` if the ratio is too large for an `i64`.
     ///
     /// # Examples
     ///
     /// ```
     /// use fractions::Fraction;
     ///
-    /// let frac = Fraction::new(3, 4)?;
-    /// let recip = frac.reciprocal()?;
-    /// assert_eq!(recip.to_string(), "4/3");
+    /// let ratio = Fraction::from_float_ratio(1.0, 3.0, 100)?;
+    /// assert_eq!(ratio, Fraction::new(1, 3)?);
     /// # Ok::<(), fractions::FractionError>(())
     /// ```
-    pub fn reciprocal(&self) -> Result<Self, FractionError> {
-        if self.numerator == 0 {
-            return Err(FractionError::DivisionByZero);
+    pub fn from_float_ratio(num: f64, den: f64, max_den: i64) -> Result<Self, FractionError> {
+        if !num.is_finite() || !den.is_finite() {
+            return Err(FractionError::NotFinite);
         }
-        Self::new(self.denominator, self.numerator)
-    }
-
-    /// Adds two fractions.
-    pub fn add(&self, other: &Self) -> Self {
-        Self {
-            numerator: self.numerator * other.denominator + other.numerator * self.denominator,
-            denominator: self.denominator * other.denominator,
+        if den == 0.0 {
+            return Err(FractionError::ZeroDenominator);
+        }
+        if max_den < 1 {
+            return Err(FractionError::InvalidArgument);
         }
-    }
 
-    /// Subtracts two fractions.
-    pub fn subtract(&self, other: &Self) -> Self {
-        Self {
-            numerator: self.numerator * other.denominator - other.numerator * self.denominator,
-            denominator: self.denominator * other.denominator,
+        let value = num / den;
+        if !value.is_finite() {
+            return Err(FractionError::Overflow);
         }
-    }
 
-    /// Multiplies two fractions.
-    pub fn multiply(&self, other: &Self) -> Self {
-        Self {
-            numerator: self.numerator * other.numerator,
-            denominator: self.denominator * other.denominator,
+        let (negative, mantissa, exponent) = decode_f64(value);
+        let (mut num, den) = if exponent >= 0 {
+            if exponent > 63 {
+                return Err(FractionError::Overflow);
+            }
+            ((mantissa as i128) << exponent, 1)
+        } else if exponent < -126 {
+            // Below 2^-73, far closer to zero than to 1/max_den
+            return Ok(Self::from_integer(0));
+        } else {
+            (mantissa as i128, 1i128 << -exponent)
+        };
+        if negative {
+            num = -num;
         }
+        Self::approximate_ratio(num, den, max_den)
     }
 
-    /// Divides two fractions.
-    pub fn divide(&self, other: &Self) -> Result<Self, FractionError> {
-        let recip = other.reciprocal()?;
-        Ok(self.multiply(&recip))
+    /// Returns the fraction closest to `value` whose denominator is at most
+    /// `max_denominator`, e.g. `1/3` for `0.3333333`.
+    ///
+    /// This is [`Fraction::from_float_ratio`] for a single value. A
+    /// `max_denominator` below one is treated as one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is NaN or infinite, or too large for an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::approximate(std::f64::consts::PI, 1000), Fraction::new(355, 113)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn approximate(value: f64, max_denominator: i64) -> Self {
+        Self::from_float_ratio(value, 1.0, max_denominator.max(1))
+            .expect("value must be finite and fit in an i64")
     }
 
-    /// Reduces the fraction to lowest terms.
+    /// Returns the best rational approximation of Euler's number `e` with a
+    /// denominator of at most `max_den`.
+    ///
+    /// The series `sum(1/k!)` is truncated in 36-digit fixed point and the
+    /// result is rounded to the bound with continued fractions, which is
+    /// accurate for denominators up to about `10^17`. `max_den` is clamped
+    /// into `[1, i64::MAX / 4]`.
     ///
     /// # Examples
     ///
     /// ```
     /// use fractions::Fraction;
     ///
-    /// let frac = Fraction::new(12, 8)?;
-    /// let reduced = frac.reduce();
-    /// assert_eq!(reduced.to_string(), "3/2");
+    /// assert_eq!(Fraction::approximate_e(10), Fraction::new(19, 7)?);
     /// # Ok::<(), fractions::FractionError>(())
     /// ```
-    pub fn reduce(&self) -> Self {
-        let gcd = gcd(self.numerator.abs(), self.denominator.abs());
-        Self {
-            numerator: self.numerator / gcd,
-            denominator: self.denominator / gcd,
+    pub fn approximate_e(max_den: i64) -> Self {
+        let mut sum = 0i128;
+        let mut term = CONSTANT_SCALE;
+        let mut k = 1;
+        while term != 0 {
+            sum += term;
+            term /= k;
+            k += 1;
         }
+        Self::approximate_constant(sum, max_den)
     }
-}
-
-/// Calculates the greatest common divisor using Euclid's algorithm.
-fn gcd(mut a: i64, mut b: i64) -> i64 {
-    while b != 0 {
-        let temp = b;
-        b = a % b;
-        a = temp;
-    }
-    a
-}
 
-impl fmt::Display for Fraction {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let reduced = self.reduce();
-        if reduced.denominator == 1 {
-            write!(f, "{}", reduced.numerator)
-        } else {
-            write!(f, "{}/{}", reduced.numerator, reduced.denominator)
-        }
+    /// Returns the best rational approximation of `π` with a denominator of at
+    /// most `max_den`.
+    ///
+    /// Machin's series `π = 16·atan(1/5) - 4·atan(1/239)` is truncated in
+    /// 36-digit fixed point and the result is rounded to the bound with
+    /// continued fractions, which is accurate for denominators up to about
+    /// `10^17`. `max_den` is clamped into `[1, i64::MAX / 4]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::approximate_pi(10), Fraction::new(22, 7)?);
+    /// assert_eq!(Fraction::approximate_pi(1000), Fraction::new(355, 113)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn approximate_pi(max_den: i64) -> Self {
+        // atan(1/x) = sum((-1)^k / ((2k + 1) * x^(2k + 1)))
+        let atan_inv = |x: i128| {
+            let mut sum = 0i128;
+            let mut power = CONSTANT_SCALE / x;
+            let mut k = 0;
+            while power != 0 {
+                let term = power / (2 * k + 1);
+                if k % 2 == 0 {
+                    sum += term;
+                } else {
+                    sum -= term;
+                }
+                power /= x * x;
+                k += 1;
+            }
+            sum
+        };
+        Self::approximate_constant(16 * atan_inv(5) - 4 * atan_inv(239), max_den)
     }
-}
 
-impl PartialEq for Fraction {
-    fn eq(&self, other: &Self) -> bool {
-        self.numerator * other.denominator == other.numerator * self.denominator
+    /// Approximates the fixed-point constant `scaled / CONSTANT_SCALE`.
+    fn approximate_constant(scaled: i128, max_den: i64) -> Self {
+        let max_den = max_den.clamp(1, i64::MAX / 4);
+        Self::approximate_ratio(scaled, CONSTANT_SCALE, max_den)
+            .expect("constants below 4 fit for clamped denominators")
     }
-}
 
-impl Eq for Fraction {}
+    /// Reconstructs a fraction from its continued-fraction coefficients `[a0; a1, a2, ...]`.
+    ///
+    /// The convergents are accumulated with checked arithmetic, so deep or
+    /// large expansions report an error instead of wrapping.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::InvalidArgument` for an empty coefficient list,
+    /// `FractionError::Overflow` if a convergent does not fit in `i64` terms, or
+    /// `FractionError::ZeroDenominator` if the coefficients describe no finite value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let approx = Fraction::from_continued_fraction(&[3, 7, 16])?;
+    /// assert_eq!(approx, Fraction::new(355, 113)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn from_continued_fraction(coeffs: &[i64]) -> Result<Self, FractionError> {
+        if coeffs.is_empty() {
+            return Err(FractionError::InvalidArgument);
+        }
 
-impl PartialOrd for Fraction {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
+        // Convergent recurrence: h_n = a_n * h_(n-1) + h_(n-2)
+        let (mut p_prev, mut p) = (0i64, 1i64);
+        let (mut q_prev, mut q) = (1i64, 0i64);
+        for &a in coeffs {
+            let p_next = a
+                .checked_mul(p)
+                .and_then(|ap| ap.checked_add(p_prev))
+                .ok_or(FractionError::Overflow)?;
+            let q_next = a
+                .checked_mul(q)
+                .and_then(|aq| aq.checked_add(q_prev))
+                .ok_or(FractionError::Overflow)?;
+            (p_prev, p) = (p, p_next);
+            (q_prev, q) = (q, q_next);
+        }
 
-impl Ord for Fraction {
-    fn cmp(&self, other: &Self) -> Ordering {
-        (self.numerator * other.denominator).cmp(&(other.numerator * self.denominator))
+        Self::from_i128(p as i128, q as i128)
     }
-}
 
-impl Add for Fraction {
-    type Output = Self;
+    /// Returns the best approximation of `num / den` with a denominator of at most `max_den`.
+    ///
+    /// Walks the continued-fraction convergents and finally compares the last
+    /// convergent against the best semiconvergent. Expects `den > 0` and `max_den >= 1`.
+    fn approximate_ratio(num: i128, den: i128, max_den: i64) -> Result<Self, FractionError> {
+        let gcd = gcd_u128(num.unsigned_abs(), den.unsigned_abs()) as i128;
+        let (num, den) = (num / gcd, den / gcd);
+        let max_den = max_den as i128;
+        if den <= max_den {
+            return Self::from_i128(num, den);
+        }
 
-    fn add(self, other: Self) -> Self::Output {
-        Self {
-            numerator: self.numerator * other.denominator + other.numerator * self.denominator,
-            denominator: self.denominator * other.denominator,
+        let (mut p0, mut q0, mut p1, mut q1) = (0i128, 1i128, 1i128, 0i128);
+        let (mut n, mut d) = (num, den);
+        loop {
+            let a = div_round(n, d, RoundingMode::Floor);
+            let q2 = match a.checked_mul(q1).and_then(|aq| aq.checked_add(q0)) {
+                Some(q2) if q2 <= max_den => q2,
+                _ => break,
+            };
+            let p2 = a
+                .checked_mul(p1)
+                .and_then(|ap| ap.checked_add(p0))
+                .ok_or(FractionError::Overflow)?;
+            (p0, q0, p1, q1) = (p1, q1, p2, q2);
+            (n, d) = (d, n - a * d);
         }
-    }
-}
 
-impl Sub for Fraction {
-    type Output = Self;
+        let k = (max_den - q0) / q1;
+        let (semi_p, semi_q) = (p0 + k * p1, q0 + k * q1);
 
-    fn sub(self, other: Self) -> Self::Output {
-        Self {
-            numerator: self.numerator * other.denominator - other.numerator * self.denominator,
-            denominator: self.denominator * other.denominator,
+        // |p/q - num/den| = |p*den - num*q| / (q*den). The residuals are smaller
+        // than `den` in magnitude, so the wrapping products cancel exactly.
+        let residual = |p: i128, q: i128| {
+            p.wrapping_mul(den)
+                .wrapping_sub(num.wrapping_mul(q))
+                .unsigned_abs()
+        };
+        let convergent_error = wide_mul(residual(p1, q1), semi_q as u128);
+        let semi_error = wide_mul(residual(semi_p, semi_q), q1 as u128);
+
+        if convergent_error <= semi_error {
+            Self::from_i128(p1, q1)
+        } else {
+            Self::from_i128(semi_p, semi_q)
         }
     }
-}
 
-impl Mul for Fraction {
-    type Output = Self;
+    /// Builds the fraction `(-1)^negative * mantissa * 2^exponent`.
+    fn from_binary_parts(
+        negative: bool,
+        mut mantissa: u64,
+        mut exponent: i32,
+    ) -> Result<Self, FractionError> {
+        if mantissa == 0 {
+            return Ok(Self::from_integer(0));
+        }
 
-    fn mul(self, other: Self) -> Self::Output {
-        Self {
-            numerator: self.numerator * other.numerator,
-            denominator: self.denominator * other.denominator,
+        // Strip factors of two shared with the denominator
+        if exponent < 0 {
+            let shift = mantissa.trailing_zeros().min(exponent.unsigned_abs());
+            mantissa >>= shift;
+            exponent += shift as i32;
         }
+
+        let (numerator, denominator) = if exponent >= 0 {
+            if exponent > 63 {
+                return Err(FractionError::Overflow);
+            }
+            ((mantissa as i128) << exponent, 1)
+        } else {
+            if exponent < -62 {
+                return Err(FractionError::Overflow);
+            }
+            (mantissa as i128, 1i128 << -exponent)
+        };
+
+        let numerator = if negative { -numerator } else { numerator };
+        Self::from_i128(numerator, denominator)
     }
-}
 
-impl Div for Fraction {
-    type Output = Result<Self, FractionError>;
+    /// Creates a reduced fraction from wide intermediate values.
+    ///
+    /// Returns `FractionError::Overflow` if the reduced terms do not fit in `i64`.
+    fn from_i128(numerator: i128, denominator: i128) -> Result<Self, FractionError> {
+        if denominator == 0 {
+            return Err(FractionError::ZeroDenominator);
+        }
 
-    fn div(self, other: Self) -> Self::Output {
-        if other.numerator == 0 {
-            return Err(FractionError::DivisionByZero);
+        let gcd = gcd_u128(numerator.unsigned_abs(), denominator.unsigned_abs()) as i128;
+        let (mut num, mut den) = (numerator / gcd, denominator / gcd);
+        if den < 0 {
+            num = num.checked_neg().ok_or(FractionError::Overflow)?;
+            den = -den;
         }
+
         Ok(Self {
-            numerator: self.numerator * other.denominator,
-            denominator: self.denominator * other.numerator,
+            numerator: i64::try_from(num).map_err(|_| FractionError::Overflow)?,
+            denominator: i64::try_from(den).map_err(|_| FractionError::Overflow)?,
         })
     }
-}
 
-impl Neg for Fraction {
-    type Output = Self;
+    /// Returns the harmonic number `H_n = 1 + 1/2 + ... + 1/n` in lowest terms.
+    ///
+    /// `H_0` is zero. The sum is reduced after every term.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::Overflow` once a partial sum no longer fits in
+    /// `i64` terms, which first happens at `n = 47`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::harmonic_number(4)?, Fraction::new(25, 12)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn harmonic_number(n: u32) -> Result<Self, FractionError> {
+        (1..=n as i64).try_fold(Self::from_integer(0), |sum, k| {
+            sum.checked_add(&Self::new(1, k)?)
+        })
+    }
+
+    /// Returns `n! / k!` in lowest terms.
+    ///
+    /// Only the factors that do not cancel are multiplied: the falling
+    /// factorial `n * (n-1) * ... * (k+1)` when `n >= k`, or its reciprocal
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::Overflow` if the product does not fit in `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::factorial_ratio(5, 3)?, Fraction::from_integer(20));
+    /// assert_eq!(Fraction::factorial_ratio(3, 5)?, Fraction::new(1, 20)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn factorial_ratio(n: u32, k: u32) -> Result<Self, FractionError> {
+        let (high, low) = (n.max(k), n.min(k));
+        let product = (low as i64 + 1..=high as i64)
+            .try_fold(1i64, |acc, factor| acc.checked_mul(factor))
+            .ok_or(FractionError::Overflow)?;
+
+        if n >= k {
+            Ok(Self::from_integer(product))
+        } else {
+            Self::new(1, product)
+        }
+    }
+
+    /// Returns the first `n` terms of the geometric progression `start, start*ratio, ...`.
+    ///
+    /// Every term is reduced to lowest terms.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::Overflow` if a term does not fit in `i64` terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let fifths = Fraction::geometric_sequence(Fraction::from_integer(1), Fraction::new(3, 2)?, 3)?;
+    /// assert_eq!(fifths[2], Fraction::new(9, 4)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn geometric_sequence(
+        start: Self,
+        ratio: Self,
+        n: usize,
+    ) -> Result<Vec<Self>, FractionError> {
+        let mut terms = Vec::with_capacity(n);
+        let mut term = start.reduce();
+        for i in 0..n {
+            if i > 0 {
+                term = term.checked_mul(&ratio)?;
+            }
+            terms.push(term);
+        }
+        Ok(terms)
+    }
+
+    /// Returns the numerator.
+    pub fn numerator(&self) -> i64 {
+        self.numerator
+    }
+
+    /// Returns the denominator.
+    pub fn denominator(&self) -> i64 {
+        self.denominator
+    }
+
+    /// Returns the terms as `(|numerator|, signed_denominator)`, with the sign on the denominator.
+    ///
+    /// This is a representation change for interop only; the terms are not reduced.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::Overflow` if the numerator is `i64::MIN`, whose
+    /// magnitude does not fit in an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::new(-3, 4)?.with_sign_on_denominator()?, (3, -4));
+    /// assert_eq!(Fraction::new(3, 4)?.with_sign_on_denominator()?, (3, 4));
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn with_sign_on_denominator(&self) -> Result<(i64, i64), FractionError> {
+        let negative = (self.numerator < 0) != (self.denominator < 0);
+        let numerator = self
+            .numerator
+            .checked_abs()
+            .ok_or(FractionError::Overflow)?;
+        let denominator = self
+            .denominator
+            .checked_abs()
+            .ok_or(FractionError::Overflow)?;
+        Ok((numerator, if negative { -denominator } else { denominator }))
+    }
+
+    /// Converts the fraction to a floating-point number.
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    /// Formats the fraction as a decimal with exactly `places` digits after the point,
+    /// rounding half away from zero.
+    fn format_decimal(&self, places: usize) -> String {
+        let negative = (self.numerator < 0) != (self.denominator < 0);
+        let num = self.numerator.unsigned_abs() as u128;
+        let den = self.denominator.unsigned_abs() as u128;
+        let (whole, digits) = round_decimal(num, den, places);
+
+        let mut out = String::new();
+        if negative && (whole != 0 || digits.iter().any(|&d| d != 0)) {
+            out.push('-');
+        }
+        push_decimal(&mut out, whole, &digits);
+        out
+    }
+
+    /// Formats the fraction as an exact decimal, enclosing the repeating digits
+    /// in parentheses, e.g. `0.1(6)` for `1/6`.
+    ///
+    /// Terminating expansions have no parentheses and integers have no point.
+    /// The repeating part of `1/d` can be up to `d - 1` digits long, so at most
+    /// 4096 digits are written after the point; a longer repeating part is cut
+    /// off with `...` before the closing parenthesis, e.g. `0.(000...)`. The
+    /// work done is linear in the number of digits written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::new(1, 6)?.to_decimal_string(), "0.1(6)");
+    /// assert_eq!(Fraction::new(-22, 7)?.to_decimal_string(), "-3.(142857)");
+    /// assert_eq!(Fraction::new(5, 4)?.to_decimal_string(), "1.25");
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn to_decimal_string(&self) -> String {
+        self.to_decimal_string_locale('.')
+    }
+
+    /// Formats the fraction like [`to_decimal_string`](Self::to_decimal_string),
+    /// using `decimal_sep` as the decimal separator. The same 4096-digit limit applies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::new(7, 6)?.to_decimal_string_locale(','), "1,1(6)");
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn to_decimal_string_locale(&self, decimal_sep: char) -> String {
+        let reduced = self.normalized();
+        let den = reduced.denominator as u128;
+        let num = reduced.numerator.unsigned_abs() as u128;
+
+        let mut out = String::new();
+        if reduced.is_negative() {
+            out.push('-');
+        }
+        out.push_str(&(num / den).to_string());
+
+        let mut rem = num % den;
+        if rem == 0 {
+            return out;
+        }
+        out.push(decimal_sep);
+
+        let next_digit = |rem: &mut u128| {
+            *rem *= 10;
+            let digit = char::from(b'0' + (*rem / den) as u8);
+            *rem %= den;
+            digit
+        };
+
+        // The digits before the repeating part are as many as the larger power of 2 or 5 in `den`
+        let (twos, fives) = (den.trailing_zeros(), count_factor(den, 5));
+        for _ in 0..twos.max(fives) {
+            out.push(next_digit(&mut rem));
+        }
+        if rem == 0 {
+            return out;
+        }
+
+        // The remaining expansion is purely periodic and returns to this remainder
+        let start = rem;
+        out.push('(');
+        for written in twos.max(fives) as usize.. {
+            if written == DECIMAL_DIGIT_LIMIT {
+                out.push_str("...");
+                break;
+            }
+            out.push(next_digit(&mut rem));
+            if rem == start {
+                break;
+            }
+        }
+        out.push(')');
+        out
+    }
+
+    /// Formats the fraction in scientific notation with `mantissa_digits` digits
+    /// after the point, e.g. `1.2345e3`.
+    ///
+    /// The mantissa is computed by exact long division and rounded half away
+    /// from zero. Zero is rendered as `0e0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::new(12345, 10)?.to_scientific_string(4), "1.2345e3");
+    /// assert_eq!(Fraction::new(-1, 8)?.to_scientific_string(2), "-1.25e-1");
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn to_scientific_string(&self, mantissa_digits: usize) -> String {
+        if self.numerator == 0 {
+            return "0e0".to_string();
+        }
+
+        let negative = (self.numerator < 0) != (self.denominator < 0);
+        let num = self.numerator.unsigned_abs() as u128;
+        let den = self.denominator.unsigned_abs() as u128;
+
+        let (mut exponent, scaled_num, scaled_den) = normalize_decimal(num, den);
+        let (mut whole, mut digits) = round_decimal(scaled_num, scaled_den, mantissa_digits);
+        if whole == 10 {
+            // Rounding carried into a new leading digit
+            whole = 1;
+            digits.iter_mut().for_each(|d| *d = 0);
+            exponent += 1;
+        }
+
+        let mut out = String::new();
+        if negative {
+            out.push('-');
+        }
+        push_decimal(&mut out, whole, &digits);
+        out.push('e');
+        out.push_str(&exponent.to_string());
+        out
+    }
+
+    /// Formats the fraction in engineering notation with `sig_figs` significant
+    /// digits, e.g. `12.3e3`, where the exponent is always a multiple of three.
+    ///
+    /// The digits are computed by exact long division and rounded half away
+    /// from zero; the integer part is padded with zeros when there are fewer
+    /// significant digits than it needs. At least one significant digit is
+    /// always shown. Zero is rendered as `0e0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::new(12345, 1)?.to_engineering_string(3), "12.3e3");
+    /// assert_eq!(Fraction::new(-1, 8)?.to_engineering_string(3), "-125e-3");
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn to_engineering_string(&self, sig_figs: usize) -> String {
+        if self.numerator == 0 {
+            return "0e0".to_string();
+        }
+
+        let negative = (self.numerator < 0) != (self.denominator < 0);
+        let num = self.numerator.unsigned_abs() as u128;
+        let den = self.denominator.unsigned_abs() as u128;
+
+        let (mut exponent, scaled_num, scaled_den) = normalize_decimal(num, den);
+        let (mut whole, mut digits) = round_decimal(scaled_num, scaled_den, sig_figs.max(1) - 1);
+        if whole == 10 {
+            // Rounding carried into a new leading digit
+            whole = 1;
+            digits.iter_mut().for_each(|d| *d = 0);
+            exponent += 1;
+        }
+        digits.insert(0, whole as u8);
+
+        // Move up to two digits in front of the point to reach a multiple of three
+        let shift = exponent.rem_euclid(3) as usize;
+        digits.resize(digits.len().max(shift + 1), 0);
+        let (int_digits, frac_digits) = digits.split_at(shift + 1);
+
+        let mut out = String::new();
+        if negative {
+            out.push('-');
+        }
+        out.extend(int_digits.iter().map(|&d| char::from(b'0' + d)));
+        if !frac_digits.is_empty() {
+            out.push('.');
+            out.extend(frac_digits.iter().map(|&d| char::from(b'0' + d)));
+        }
+        out.push('e');
+        out.push_str(&(exponent - shift as i32).to_string());
+        out
+    }
+
+    /// Formats the fraction like `Display`, appending `suffix` only when the value is an integer.
+    ///
+    /// The suffix is appended verbatim, so include any separating space in it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::new(10, 2)?.to_string_with_integer_suffix(" units"), "5 units");
+    /// assert_eq!(Fraction::new(3, 4)?.to_string_with_integer_suffix(" units"), "3/4");
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn to_string_with_integer_suffix(&self, suffix: &str) -> String {
+        let reduced = self.normalized();
+        if reduced.denominator == 1 {
+            format!("{}{}", reduced.numerator, suffix)
+        } else {
+            reduced.to_string()
+        }
+    }
+
+    /// Formats this fraction and `other` over their least common denominator
+    /// for side-by-side display.
+    ///
+    /// The terms are scaled in `i128`, so the strings are exact even when the
+    /// common denominator does not fit in `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let (a, b) = Fraction::new(1, 2)?.format_pair(&Fraction::new(1, 3)?);
+    /// assert_eq!((a.as_str(), b.as_str()), ("3/6", "2/6"));
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn format_pair(&self, other: &Self) -> (String, String) {
+        let (a, b) = (self.normalized(), other.normalized());
+        let (a_den, b_den) = (a.denominator as i128, b.denominator as i128);
+        let common = a_den / gcd_u128(a_den as u128, b_den as u128) as i128 * b_den;
+
+        let format = |frac: Self, den: i128| {
+            format!("{}/{}", frac.numerator as i128 * (common / den), common)
+        };
+        (format(a, a_den), format(b, b_den))
+    }
+
+    /// Formats the fraction as a mixed number such as `3 1/2`.
+    ///
+    /// Negative values put the sign on the whole part (`-3 1/2`), whole
+    /// numbers omit the fraction and proper fractions omit the whole part.
+    /// `Display` keeps the improper form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::new(-7, 2)?.to_mixed_string(), "-3 1/2");
+    /// assert_eq!(Fraction::new(4, 2)?.to_mixed_string(), "2");
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn to_mixed_string(&self) -> String {
+        let (whole, rest) = self.split_integer();
+        if whole == 0 {
+            rest.to_string()
+        } else if rest.numerator == 0 {
+            whole.to_string()
+        } else {
+            format!("{} {}", whole, rest.abs())
+        }
+    }
+
+    /// Formats the fraction using the accounting convention of wrapping
+    /// negative values in parentheses instead of using a minus sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::new(-6, 8)?.to_accounting_string(), "(3/4)");
+    /// assert_eq!(Fraction::new(6, 8)?.to_accounting_string(), "3/4");
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn to_accounting_string(&self) -> String {
+        let reduced = self.normalized();
+        if reduced.is_negative() {
+            format!("({})", reduced.abs())
+        } else {
+            reduced.to_string()
+        }
+    }
+
+    /// Formats the fraction with a superscript numerator, a fraction slash and a
+    /// subscript denominator, e.g. `³⁄₄`.
+    ///
+    /// Integers are rendered with plain digits, and negative values get a
+    /// leading `-`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::new(3, 4)?.to_superscript_string(), "³⁄₄");
+    /// assert_eq!(Fraction::new(-10, 2)?.to_superscript_string(), "-5");
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn to_superscript_string(&self) -> String {
+        const SUPERSCRIPT: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+        const SUBSCRIPT: [char; 10] = ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'];
+
+        let reduced = self.normalized();
+        if reduced.denominator == 1 {
+            return reduced.to_string();
+        }
+
+        let map_digits = |n: u64, table: &[char; 10]| -> String {
+            n.to_string()
+                .bytes()
+                .map(|b| table[(b - b'0') as usize])
+                .collect()
+        };
+
+        let mut out = String::new();
+        if reduced.is_negative() {
+            out.push('-');
+        }
+        out.push_str(&map_digits(reduced.numerator.unsigned_abs(), &SUPERSCRIPT));
+        out.push('\u{2044}');
+        out.push_str(&map_digits(reduced.denominator.unsigned_abs(), &SUBSCRIPT));
+        out
+    }
+
+    /// Writes a value in `[0, 1)` with the Roman duodecimal fraction symbols.
+    ///
+    /// Each uncia (`1/12`) is a dot `·`, the semis (`1/2`) is `S` and the
+    /// semuncia (`1/24`) is `Σ`, so `7/12` is `S·` and `1/24` is `Σ`. Zero is
+    /// written `N` for *nulla*. Returns `None` for values outside `[0, 1)` or
+    /// that are not a whole number of semunciae.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::new(1, 12)?.to_roman_uncia().as_deref(), Some("·"));
+    /// assert_eq!(Fraction::new(3, 4)?.to_roman_uncia().as_deref(), Some("S···"));
+    /// assert_eq!(Fraction::new(1, 5)?.to_roman_uncia(), None);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn to_roman_uncia(&self) -> Option<String> {
+        let value = self.normalized();
+        if value.numerator < 0
+            || value.numerator >= value.denominator
+            || 24 % value.denominator != 0
+        {
+            return None;
+        }
+
+        let semunciae = value.numerator * (24 / value.denominator);
+        if semunciae == 0 {
+            return Some("N".to_string());
+        }
+
+        let mut out = String::new();
+        if semunciae >= 12 {
+            out.push('S');
+        }
+        for _ in 0..(semunciae % 12) / 2 {
+            out.push('·');
+        }
+        if semunciae % 2 == 1 {
+            out.push('Σ');
+        }
+        Some(out)
+    }
+
+    /// Formats the fraction in base 60 using the modern notation for Babylonian
+    /// numbers, e.g. `1;30` for `3/2`.
+    ///
+    /// The integer part is written in decimal, followed by a semicolon and
+    /// `places` comma-separated sexagesimal digits. The expansion is truncated
+    /// after `places` digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::new(3, 2)?.to_sexagesimal(1), "1;30");
+    /// assert_eq!(Fraction::new(1, 7)?.to_sexagesimal(3), "0;8,34,17");
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn to_sexagesimal(&self, places: usize) -> String {
+        let reduced = self.normalized();
+        let den = reduced.denominator as u128;
+        let num = reduced.numerator.unsigned_abs() as u128;
+
+        let mut out = String::new();
+        if reduced.is_negative() {
+            out.push('-');
+        }
+        out.push_str(&(num / den).to_string());
+
+        let mut rem = num % den;
+        for place in 0..places {
+            out.push(if place == 0 { ';' } else { ',' });
+            rem *= 60;
+            out.push_str(&(rem / den).to_string());
+            rem %= den;
+        }
+        out
+    }
+
+    /// Returns the exact amount by which `to_f64` rounds, i.e. `self - to_f64()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::Overflow` if the error cannot be represented with
+    /// `i64` terms, which happens for fractions with very large denominators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert!(Fraction::new(3, 8)?.f64_conversion_error()?.is_zero());
+    /// assert!(!Fraction::new(1, 3)?.f64_conversion_error()?.is_zero());
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn f64_conversion_error(&self) -> Result<Self, FractionError> {
+        self.checked_sub(&Self::from_f64_exact(self.to_f64())?)
+    }
+
+    /// Adds the exact value of a floating-point number to the fraction.
+    ///
+    /// The float is decoded with [`from_f64_exact`](Self::from_f64_exact), so the
+    /// result stays exact instead of falling back to floating-point arithmetic.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::NotFinite` for NaN or infinite values, or
+    /// `FractionError::Overflow` if the sum does not fit in `i64` terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let third = Fraction::new(1, 3)?;
+    /// assert_eq!(third.add_f64_exact(0.5)?, Fraction::new(5, 6)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn add_f64_exact(&self, x: f64) -> Result<Self, FractionError> {
+        self.checked_add(&Self::from_f64_exact(x)?)
+    }
+
+    /// Applies the Möbius transform `(a*self + b) / (c*self + d)` exactly.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::DivisionByZero` if `c*self + d` is zero, or
+    /// `FractionError::Overflow` if the result does not fit in `i64` terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let x = Fraction::new(1, 2)?;
+    /// assert_eq!(x.mobius(2, 1, 1, 3)?, Fraction::new(4, 7)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn mobius(&self, a: i64, b: i64, c: i64, d: i64) -> Result<Self, FractionError> {
+        let (n, m) = (self.numerator as i128, self.denominator as i128);
+        let linear = |x: i64, y: i64| {
+            (x as i128 * n)
+                .checked_add(y as i128 * m)
+                .ok_or(FractionError::Overflow)
+        };
+
+        let numerator = linear(a, b)?;
+        let denominator = linear(c, d)?;
+        if denominator == 0 {
+            return Err(FractionError::DivisionByZero);
+        }
+        Self::from_i128(numerator, denominator)
+    }
+
+    /// Rounds to the nearest multiple of `step`, breaking ties towards the even multiple.
+    ///
+    /// This is exact bankers' rounding: a value exactly halfway between two
+    /// multiples of `step` goes to the one with an even quotient.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::DivisionByZero` if `step` is zero, or
+    /// `FractionError::Overflow` if the result does not fit in `i64` terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let cent = Fraction::new(1, 100)?;
+    /// let value = Fraction::new(5, 1000)?;
+    /// assert!(value.round_to_step_half_even(&cent)?.is_zero());
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn round_to_step_half_even(&self, step: &Self) -> Result<Self, FractionError> {
+        let (num, den) = self.quotient_terms(step)?;
+        let multiple = div_round(num, den, RoundingMode::HalfToEven);
+        let numerator = multiple
+            .checked_mul(step.numerator as i128)
+            .ok_or(FractionError::Overflow)?;
+        Self::from_i128(numerator, step.denominator as i128)
+    }
+
+    /// Returns `floor(self / other)` as an integer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::DivisionByZero` if `other` is zero, or
+    /// `FractionError::Overflow` if the quotient does not fit in an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let one = Fraction::from_integer(1);
+    /// assert_eq!(Fraction::new(7, 2)?.floor_div(&one)?, 3);
+    /// assert_eq!(Fraction::new(-7, 2)?.floor_div(&one)?, -4);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn floor_div(&self, other: &Self) -> Result<i64, FractionError> {
+        let (num, den) = self.quotient_terms(other)?;
+        i64::try_from(div_round(num, den, RoundingMode::Floor)).map_err(|_| FractionError::Overflow)
+    }
+
+    /// Returns `ceil(self / other)` as an integer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::DivisionByZero` if `other` is zero, or
+    /// `FractionError::Overflow` if the quotient does not fit in an `i64`.
+    pub fn ceil_div(&self, other: &Self) -> Result<i64, FractionError> {
+        let (num, den) = self.quotient_terms(other)?;
+        i64::try_from(div_round(num, den, RoundingMode::Ceiling))
+            .map_err(|_| FractionError::Overflow)
+    }
+
+    /// Converts between units related by an integer ratio, computing
+    /// `self * to_unit / from_unit` exactly and in lowest terms.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::DivisionByZero` if `from_unit` is zero, or
+    /// `FractionError::Overflow` if the result does not fit in `i64` terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// // 3/2 metres in centimetres
+    /// assert_eq!(Fraction::new(3, 2)?.convert(1, 100)?, Fraction::from_integer(150));
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn convert(&self, from_unit: i64, to_unit: i64) -> Result<Self, FractionError> {
+        if from_unit == 0 {
+            return Err(FractionError::DivisionByZero);
+        }
+        Self::from_i128(
+            self.numerator as i128 * to_unit as i128,
+            self.denominator as i128 * from_unit as i128,
+        )
+    }
+
+    /// Returns the relative change from `from` to `self` in percent, `(self - from) / from * 100`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::DivisionByZero` if `from` is zero, or
+    /// `FractionError::Overflow` if the result does not fit in `i64` terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let before = Fraction::from_integer(4);
+    /// let after = Fraction::from_integer(5);
+    /// assert_eq!(after.percentage_change(&before)?, Fraction::from_integer(25));
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn percentage_change(&self, from: &Self) -> Result<Self, FractionError> {
+        if from.numerator == 0 {
+            return Err(FractionError::DivisionByZero);
+        }
+
+        // (a/b - c/d) / (c/d) = (a*d - c*b) / (b*c)
+        let difference = (self.numerator as i128 * from.denominator as i128)
+            .checked_sub(from.numerator as i128 * self.denominator as i128)
+            .and_then(|diff| diff.checked_mul(100))
+            .ok_or(FractionError::Overflow)?;
+        Self::from_i128(
+            difference,
+            self.denominator as i128 * from.numerator as i128,
+        )
+    }
+
+    /// Snaps the fraction to the nearest multiple of `1/d`, rounding halfway cases away from zero.
+    ///
+    /// The result is reduced, so its denominator divides `d`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::ZeroDenominator` if `d` is zero, or
+    /// `FractionError::Overflow` if the result does not fit in `i64` terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let value = Fraction::new(3, 8)?;
+    /// assert_eq!(value.round_to_denominator(16)?, value);
+    /// assert_eq!(value.round_to_denominator(4)?, Fraction::new(1, 2)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn round_to_denominator(&self, d: i64) -> Result<Self, FractionError> {
+        if d == 0 {
+            return Err(FractionError::ZeroDenominator);
+        }
+        let units = div_round(
+            self.numerator as i128 * d as i128,
+            self.denominator as i128,
+            RoundingMode::HalfAwayFromZero,
+        );
+        Self::from_i128(units, d as i128)
+    }
+
+    /// Wraps the fraction into the half-open range `[lo, hi)` with periodic semantics.
+    ///
+    /// The result is `self - k * (hi - lo)` for the unique integer `k` that
+    /// places it inside the range, like an angle wrapped into one turn.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::InvalidArgument` if `lo >= hi`, or
+    /// `FractionError::Overflow` if an intermediate result does not fit in `i64` terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let lo = Fraction::from_integer(0);
+    /// let hi = Fraction::from_integer(1);
+    /// assert_eq!(Fraction::new(5, 2)?.wrap_into_range(&lo, &hi)?, Fraction::new(1, 2)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn wrap_into_range(&self, lo: &Self, hi: &Self) -> Result<Self, FractionError> {
+        if lo.safe_cmp(hi) != Ordering::Less {
+            return Err(FractionError::InvalidArgument);
+        }
+
+        let width = hi.checked_sub(lo)?;
+        let periods = self.checked_sub(lo)?.floor_div(&width)?;
+        self.checked_sub(&width.checked_mul(&Self::from_integer(periods))?)
+    }
+
+    /// Clamps the fraction into `[lo, hi]` and then snaps it to the nearest multiple of `step`.
+    ///
+    /// Halfway cases round away from zero. If the nearest multiple falls outside
+    /// the range, the closest multiple inside the range is used instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::DivisionByZero` if `step` is zero,
+    /// `FractionError::InvalidArgument` if `lo > hi` or no multiple of `step`
+    /// lies within the range, or `FractionError::Overflow` if an intermediate
+    /// result does not fit in `i64` terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let (lo, hi) = (Fraction::from_integer(0), Fraction::from_integer(1));
+    /// let step = Fraction::new(1, 4)?;
+    /// let value = Fraction::new(3, 10)?;
+    /// assert_eq!(value.clamp_and_quantize(&lo, &hi, &step)?, Fraction::new(1, 4)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn clamp_and_quantize(
+        &self,
+        lo: &Self,
+        hi: &Self,
+        step: &Self,
+    ) -> Result<Self, FractionError> {
+        if step.is_zero() {
+            return Err(FractionError::DivisionByZero);
+        }
+        if lo.safe_cmp(hi) == Ordering::Greater {
+            return Err(FractionError::InvalidArgument);
+        }
+
+        let step = step.abs();
+        let clamped = if self.safe_cmp(lo) == Ordering::Less {
+            *lo
+        } else if self.safe_cmp(hi) == Ordering::Greater {
+            *hi
+        } else {
+            *self
+        };
+
+        let (num, den) = clamped.quotient_terms(&step)?;
+        let multiple = |k: i128| {
+            i64::try_from(k)
+                .map_err(|_| FractionError::Overflow)
+                .and_then(|k| step.checked_mul(&Self::from_integer(k)))
+        };
+
+        let mut snapped = multiple(div_round(num, den, RoundingMode::HalfAwayFromZero))?;
+        if snapped.safe_cmp(hi) == Ordering::Greater {
+            snapped = multiple(hi.floor_div(&step)?.into())?;
+        } else if snapped.safe_cmp(lo) == Ordering::Less {
+            snapped = multiple(lo.ceil_div(&step)?.into())?;
+        }
+
+        if snapped.safe_cmp(lo) == Ordering::Less || snapped.safe_cmp(hi) == Ordering::Greater {
+            return Err(FractionError::InvalidArgument);
+        }
+        Ok(snapped)
+    }
+
+    /// Converts the fraction to a Q-format fixed-point value with `frac_bits`
+    /// fractional bits, i.e. `round(self * 2^frac_bits)`.
+    ///
+    /// Halfway cases round away from zero. Returns `None` if the result does
+    /// not fit in an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::new(3, 2)?.to_dyadic_parts(16), Some(98304));
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn to_dyadic_parts(&self, frac_bits: u32) -> Option<i64> {
+        if self.numerator == 0 {
+            return Some(0);
+        }
+
+        // |n| * 2^frac_bits >= 2^126 already exceeds i64::MAX * |d|
+        let bits = 64 - self.numerator.unsigned_abs().leading_zeros();
+        if bits.checked_add(frac_bits)? > 126 {
+            return None;
+        }
+
+        let scaled = div_round(
+            (self.numerator as i128) << frac_bits,
+            self.denominator as i128,
+            RoundingMode::HalfAwayFromZero,
+        );
+        i64::try_from(scaled).ok()
+    }
+
+    /// Rounds the fraction to an integer using the given rounding mode.
+    ///
+    /// The computation is exact, so halfway cases are detected precisely.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::{Fraction, RoundingMode};
+    ///
+    /// let value = Fraction::new(5, 2)?;
+    /// assert_eq!(value.round_with(RoundingMode::HalfToEven), 2);
+    /// assert_eq!(value.round_with(RoundingMode::HalfToOdd), 3);
+    /// assert_eq!((-value).round_with(RoundingMode::HalfDown), -3);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn round_with(&self, mode: RoundingMode) -> i64 {
+        div_round(self.numerator as i128, self.denominator as i128, mode) as i64
+    }
+
+    /// Returns the largest integer less than or equal to the fraction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::new(-7, 2)?.floor(), -4);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn floor(&self) -> i64 {
+        self.round_with(RoundingMode::Floor)
+    }
+
+    /// Returns the smallest integer greater than or equal to the fraction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::new(-7, 2)?.ceil(), -3);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn ceil(&self) -> i64 {
+        self.round_with(RoundingMode::Ceiling)
+    }
+
+    /// Returns the integer part of the fraction, rounding towards zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::new(-7, 2)?.trunc(), -3);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn trunc(&self) -> i64 {
+        self.round_with(RoundingMode::TowardZero)
+    }
+
+    /// Rounds the fraction to the nearest integer, with halfway cases rounded
+    /// away from zero like [`f64::round`].
+    ///
+    /// Use [`Fraction::round_with`] for other tie-breaking rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::new(-7, 2)?.round(), -4);
+    /// assert_eq!(Fraction::new(5, 3)?.round(), 2);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn round(&self) -> i64 {
+        self.round_with(RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Returns the unreduced terms of `self / other` in `i128`.
+    fn quotient_terms(&self, other: &Self) -> Result<(i128, i128), FractionError> {
+        if other.numerator == 0 {
+            return Err(FractionError::DivisionByZero);
+        }
+        Ok((
+            self.numerator as i128 * other.denominator as i128,
+            self.denominator as i128 * other.numerator as i128,
+        ))
+    }
+
+    /// Returns the absolute value of the fraction.
+    pub fn abs(&self) -> Self {
+        Self {
+            numerator: self.numerator.abs(),
+            denominator: self.denominator,
+        }
+    }
+
+    /// Restricts the fraction to the interval `[low, high]`.
+    ///
+    /// Comparisons are exact, see [`Fraction::safe_cmp`]. `low` must not
+    /// exceed `high`; this is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let (low, high) = (Fraction::ZERO, Fraction::ONE);
+    /// assert_eq!(Fraction::new(3, 2)?.clamp(low, high), Fraction::ONE);
+    /// assert_eq!(Fraction::new(1, 2)?.clamp(low, high), Fraction::new(1, 2)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn clamp(self, low: Self, high: Self) -> Self {
+        debug_assert!(low <= high, "clamp requires low <= high");
+        if self.safe_cmp(&low) == Ordering::Less {
+            low
+        } else if self.safe_cmp(&high) == Ordering::Greater {
+            high
+        } else {
+            self
+        }
+    }
+
+    /// Returns the smaller of two fractions, or `self` if they are equal.
+    pub fn min(self, other: Self) -> Self {
+        if other.safe_cmp(&self) == Ordering::Less {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the larger of two fractions, or `other` if they are equal.
+    pub fn max(self, other: Self) -> Self {
+        if self.safe_cmp(&other) == Ordering::Greater {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Limits the magnitude of the fraction to `max_abs`, keeping its sign.
+    ///
+    /// `max_abs` is expected to be non-negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let limit = Fraction::from_integer(2);
+    /// assert_eq!(Fraction::new(-7, 2)?.clamp_magnitude(&limit), -limit);
+    /// assert_eq!(Fraction::new(3, 2)?.clamp_magnitude(&limit), Fraction::new(3, 2)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn clamp_magnitude(&self, max_abs: &Self) -> Self {
+        if self.abs() <= *max_abs {
+            *self
+        } else if self.is_negative() {
+            -*max_abs
+        } else {
+            *max_abs
+        }
+    }
+
+    /// Interpolates linearly between `self` and `other`, clamping `t` into `[0, 1]` first.
+    ///
+    /// Values of `t` outside the unit interval never extrapolate: `t <= 0`
+    /// yields `self` and `t >= 1` yields `other`. The result is computed as
+    /// `self * (1 - t) + other * t`, so the endpoints' difference is never formed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::Overflow` if an intermediate result does not fit in `i64` terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let start = Fraction::from_integer(1);
+    /// let end = Fraction::from_integer(2);
+    /// assert_eq!(start.lerp_clamped(&end, &Fraction::new(1, 4)?)?, Fraction::new(5, 4)?);
+    /// assert_eq!(start.lerp_clamped(&end, &Fraction::new(3, 2)?)?, end);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn lerp_clamped(&self, other: &Self, t: &Self) -> Result<Self, FractionError> {
+        let t = (*t).clamp(Self::ZERO, Self::ONE);
+        if t.is_zero() {
+            return Ok(self.reduce());
+        }
+        if t == Self::ONE {
+            return Ok(other.reduce());
+        }
+        let kept = self.checked_mul(&Self::ONE.checked_sub(&t)?)?;
+        kept.checked_add(&other.checked_mul(&t)?)
+    }
+
+    /// Compares two fractions without any possibility of overflow or panic.
+    ///
+    /// The cross-multiplication is carried out in `i128`, where the product of
+    /// two `i64` values always fits, so the result is correct for every pair of
+    /// fractions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    /// use std::cmp::Ordering;
+    ///
+    /// let a = Fraction::new(i64::MAX, 2)?;
+    /// let b = Fraction::new(i64::MAX, 3)?;
+    /// assert_eq!(a.safe_cmp(&b), Ordering::Greater);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn safe_cmp(&self, other: &Self) -> Ordering {
+        let lhs = self.numerator as i128 * other.denominator as i128;
+        let rhs = other.numerator as i128 * self.denominator as i128;
+        if (self.denominator < 0) == (other.denominator < 0) {
+            lhs.cmp(&rhs)
+        } else {
+            rhs.cmp(&lhs)
+        }
+    }
+
+    /// Compares two fractions by expanding their continued fractions term by
+    /// term, without any multiplication.
+    ///
+    /// The first differing coefficient decides the order; its direction flips
+    /// at every level of the expansion. This never overflows, but
+    /// [`Fraction::safe_cmp`] is usually faster.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    /// use std::cmp::Ordering;
+    ///
+    /// let a = Fraction::new(i64::MAX, i64::MAX - 1)?;
+    /// let b = Fraction::new(i64::MAX - 1, i64::MAX - 2)?;
+    /// assert_eq!(a.cmp_lexicographic(&b), Ordering::Less);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn cmp_lexicographic(&self, other: &Self) -> Ordering {
+        let (x, y) = (self.normalized(), other.normalized());
+        let (mut a, mut b, mut c, mut d) = (x.numerator, x.denominator, y.numerator, y.denominator);
+        let mut flipped = false;
+        loop {
+            let (q1, q2) = (a.div_euclid(b), c.div_euclid(d));
+            let (r1, r2) = (a.rem_euclid(b), c.rem_euclid(d));
+            let ordering = match (r1, r2) {
+                _ if q1 != q2 => q1.cmp(&q2),
+                (0, 0) => return Ordering::Equal,
+                (0, _) => Ordering::Less,
+                (_, 0) => Ordering::Greater,
+                _ => {
+                    // Compare the reciprocals of the remainders one level down
+                    (a, b, c, d) = (b, r1, d, r2);
+                    flipped = !flipped;
+                    continue;
+                }
+            };
+            return if flipped {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+        }
+    }
+
+    /// Compares two fractions and returns the ordering together with the
+    /// absolute difference between them, reduced to lowest terms.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::Overflow` if the difference does not fit in `i64` terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (order, diff) = Fraction::new(1, 2)?.compare_detailed(&Fraction::new(1, 3)?)?;
+    /// assert_eq!(order, Ordering::Greater);
+    /// assert_eq!(diff, Fraction::new(1, 6)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn compare_detailed(&self, other: &Self) -> Result<(Ordering, Self), FractionError> {
+        let order = self.safe_cmp(other);
+        let diff = match order {
+            Ordering::Less => other.checked_sub(self)?,
+            _ => self.checked_sub(other)?,
+        };
+        Ok((order, diff))
+    }
+
+    /// Returns a sort key that orders fractions consistently with `f64::total_cmp`.
+    ///
+    /// The first component is the bit pattern of [`to_f64`](Self::to_f64),
+    /// transformed so that comparing it as an integer matches `f64::total_cmp`.
+    /// The second component is the exact fraction, which breaks ties between
+    /// distinct fractions that round to the same double. Sorting by this key
+    /// therefore interleaves correctly with float-sorted data while keeping
+    /// exact distinctions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let mut values = vec![Fraction::new(1, 2)?, Fraction::new(-3, 4)?, Fraction::new(1, 3)?];
+    /// values.sort_by_key(Fraction::f64_total_order_key);
+    /// assert_eq!(values[0], Fraction::new(-3, 4)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn f64_total_order_key(&self) -> (i64, Self) {
+        let bits = self.to_f64().to_bits() as i64;
+        // Flip the magnitude bits of negative values, as `f64::total_cmp` does
+        let key = bits ^ (((bits >> 63) as u64) >> 1) as i64;
+        (key, self.reduce())
+    }
+
+    /// Checks if the fraction is positive.
+    pub fn is_positive(&self) -> bool {
+        self.numerator > 0
+    }
+
+    /// Checks if the fraction is negative.
+    pub fn is_negative(&self) -> bool {
+        self.numerator < 0
+    }
+
+    /// Checks if the fraction is zero.
+    pub fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    /// Checks if the fraction is a whole number, whether or not it is reduced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert!(Fraction::new_unreduced(4, 2)?.is_integer());
+    /// assert!(!Fraction::new(1, 2)?.is_integer());
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn is_integer(&self) -> bool {
+        self.numerator % self.denominator == 0
+    }
+
+    /// Returns `1/1`, `0/1` or `-1/1` according to the sign of the fraction,
+    /// like [`i64::signum`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::new(-3, 4)?.signum(), Fraction::from_integer(-1));
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn signum(&self) -> Self {
+        Self::from_integer(self.numerator.signum() * self.denominator.signum())
+    }
+
+    /// Returns the reciprocal of the fraction.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::DivisionByZero` if the numerator is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let frac = Fraction::new(3, 4)?;
+    /// let recip = frac.reciprocal()?;
+    /// assert_eq!(recip.to_string(), "4/3");
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn reciprocal(&self) -> Result<Self, FractionError> {
+        if self.numerator == 0 {
+            return Err(FractionError::DivisionByZero);
+        }
+        Self::new(self.denominator, self.numerator)
+    }
+
+    /// Returns the reciprocal of a fraction known to be nonzero.
+    ///
+    /// Use [`Fraction::reciprocal`] when the fraction may be zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fraction is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::new(-3, 4)?.recip(), Fraction::new(-4, 3)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn recip(&self) -> Self {
+        self.reciprocal()
+            .expect("cannot take the reciprocal of a zero fraction")
+    }
+
+    /// Adds two fractions.
+    ///
+    /// Panics if the reduced sum does not fit in `i64` terms; use
+    /// [`Fraction::checked_add`] to get an error instead.
+    pub fn add(&self, other: &Self) -> Self {
+        self.add_terms(other.numerator as i128, other.denominator as i128)
+    }
+
+    /// Subtracts two fractions.
+    ///
+    /// Panics if the reduced difference does not fit in `i64` terms; use
+    /// [`Fraction::checked_sub`] to get an error instead.
+    pub fn subtract(&self, other: &Self) -> Self {
+        self.add_terms(-(other.numerator as i128), other.denominator as i128)
+    }
+
+    /// Multiplies two fractions.
+    ///
+    /// Panics if the reduced product does not fit in `i64` terms; use
+    /// [`Fraction::checked_mul`] to get an error instead.
+    pub fn multiply(&self, other: &Self) -> Self {
+        self.mul_terms(other.numerator as i128, other.denominator as i128)
+    }
+
+    /// Divides two fractions.
+    ///
+    /// Panics if the reduced quotient does not fit in `i64` terms; use
+    /// [`Fraction::checked_div`] to get an error instead.
+    pub fn divide(&self, other: &Self) -> Result<Self, FractionError> {
+        if other.numerator == 0 {
+            return Err(FractionError::DivisionByZero);
+        }
+        Ok(self.mul_terms(other.denominator as i128, other.numerator as i128))
+    }
+
+    /// Returns the remainder of `self / other`, i.e. `self - (self / other).trunc() * other`.
+    ///
+    /// Like `%` on integers, the result takes the sign of the dividend.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::DivisionByZero` if `other` is zero, or
+    /// `FractionError::Overflow` if the reduced remainder does not fit in `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let rem = Fraction::new(7, 2)?.rem(&Fraction::new(1, 3)?)?;
+    /// assert_eq!(rem, Fraction::new(1, 6)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn rem(&self, other: &Self) -> Result<Self, FractionError> {
+        let (num, den) = self.quotient_terms(other)?;
+        // num / den is self / other; the truncated remainder of that division
+        // is the numerator of the result over the common denominator.
+        let numerator = if den < 0 { num % -den } else { num % den };
+        Self::from_i128(
+            numerator,
+            self.denominator as i128 * other.denominator as i128,
+        )
+    }
+
+    /// Divides the fraction in place by `other`, the fallible counterpart of `/=`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::DivisionByZero` if `other` is zero, in which
+    /// case `self` is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let mut frac = Fraction::new(3, 4)?;
+    /// frac.checked_div_assign(&Fraction::new(3, 2)?)?;
+    /// assert_eq!(frac, Fraction::new(1, 2)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn checked_div_assign(&mut self, other: &Self) -> Result<(), FractionError> {
+        *self = self.divide(other)?;
+        Ok(())
+    }
+
+    /// Adds `numerator/denominator`, dividing out the gcd of the denominators
+    /// first so that reduced inputs give a reduced sum. The terms are `i64`
+    /// values widened to `i128`, where every intermediate fits.
+    fn add_terms(&self, numerator: i128, denominator: i128) -> Self {
+        let (a, b) = (self.numerator as i128, self.denominator as i128);
+        let g = gcd_u128(b.unsigned_abs(), denominator.unsigned_abs()) as i128;
+        let t = a * (denominator / g) + numerator * (b / g);
+        let g2 = gcd_u128(t.unsigned_abs(), g.unsigned_abs()) as i128;
+        Self::with_positive_denominator(t / g2, (b / g) * (denominator / g2))
+    }
+
+    /// Multiplies by `numerator/denominator`, cancelling the cross terms first.
+    fn mul_terms(&self, numerator: i128, denominator: i128) -> Self {
+        let (a, b) = (self.numerator as i128, self.denominator as i128);
+        let g1 = gcd_u128(a.unsigned_abs(), denominator.unsigned_abs()) as i128;
+        let g2 = gcd_u128(numerator.unsigned_abs(), b.unsigned_abs()) as i128;
+        Self::with_positive_denominator((a / g1) * (numerator / g2), (b / g2) * (denominator / g1))
+    }
+
+    /// Moves the sign of `numerator/denominator` onto the numerator and narrows
+    /// the terms back to `i64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics in every build profile if either term does not fit in an `i64`.
+    fn with_positive_denominator(numerator: i128, denominator: i128) -> Self {
+        let (numerator, denominator) = if denominator < 0 {
+            (-numerator, -denominator)
+        } else {
+            (numerator, denominator)
+        };
+        match (i64::try_from(numerator), i64::try_from(denominator)) {
+            (Ok(numerator), Ok(denominator)) => Self {
+                numerator,
+                denominator,
+            },
+            _ => panic!("fraction arithmetic overflowed i64; use the checked_* methods"),
+        }
+    }
+
+    /// Raises the fraction to an integer power, returning the result in lowest terms.
+    ///
+    /// `exp == 0` gives `1/1` and negative exponents raise the reciprocal. The
+    /// terms are computed by exponentiation by squaring.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::DivisionByZero` for a negative power of zero, or
+    /// `FractionError::Overflow` if the result does not fit in `i64` terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let frac = Fraction::new(2, 3)?;
+    /// assert_eq!(frac.pow(3)?, Fraction::new(8, 27)?);
+    /// assert_eq!(frac.pow(-2)?, Fraction::new(9, 4)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn pow(&self, exp: i32) -> Result<Self, FractionError> {
+        let base = if exp < 0 {
+            self.reciprocal()?
+        } else {
+            self.normalized()
+        };
+
+        // Powers of coprime terms stay coprime, so no further reduction is needed
+        let exp = exp.unsigned_abs();
+        Ok(Self {
+            numerator: base
+                .numerator
+                .checked_pow(exp)
+                .ok_or(FractionError::Overflow)?,
+            denominator: base
+                .denominator
+                .checked_pow(exp)
+                .ok_or(FractionError::Overflow)?,
+        })
+    }
+
+    /// Adds two fractions, returning the sum in lowest terms.
+    ///
+    /// The cross products are formed in `i128`, so only a result whose reduced
+    /// terms do not fit in `i64` is reported as overflow.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FractionError::Overflow`] if the reduced sum does not fit in `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::{Fraction, FractionError};
+    ///
+    /// let sum = Fraction::new(1, 6)?.checked_add(&Fraction::new(1, 3)?)?;
+    /// assert_eq!(sum.to_string(), "1/2");
+    ///
+    /// let big = Fraction::new(1, i64::MAX)?;
+    /// assert_eq!(big.checked_add(&Fraction::new(1, i64::MAX - 1)?), Err(FractionError::Overflow));
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn checked_add(&self, other: &Self) -> Result<Self, FractionError> {
+        let numerator = self.numerator as i128 * other.denominator as i128
+            + other.numerator as i128 * self.denominator as i128;
+        Self::from_i128(
+            numerator,
+            self.denominator as i128 * other.denominator as i128,
+        )
+    }
+
+    /// Subtracts two fractions, returning the difference in lowest terms.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FractionError::Overflow`] if the reduced difference does not fit in `i64`.
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, FractionError> {
+        let numerator = self.numerator as i128 * other.denominator as i128
+            - other.numerator as i128 * self.denominator as i128;
+        Self::from_i128(
+            numerator,
+            self.denominator as i128 * other.denominator as i128,
+        )
+    }
+
+    /// Multiplies two fractions, returning the product in lowest terms.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FractionError::Overflow`] if the reduced product does not fit in `i64`.
+    pub fn checked_mul(&self, other: &Self) -> Result<Self, FractionError> {
+        Self::from_i128(
+            self.numerator as i128 * other.numerator as i128,
+            self.denominator as i128 * other.denominator as i128,
+        )
+    }
+
+    /// Divides two fractions, returning the quotient in lowest terms.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FractionError::DivisionByZero`] if `other` is zero, or
+    /// [`FractionError::Overflow`] if the reduced quotient does not fit in `i64`.
+    pub fn checked_div(&self, other: &Self) -> Result<Self, FractionError> {
+        if other.numerator == 0 {
+            return Err(FractionError::DivisionByZero);
+        }
+        Self::from_i128(
+            self.numerator as i128 * other.denominator as i128,
+            self.denominator as i128 * other.numerator as i128,
+        )
+    }
+
+    /// Reduces the fraction to lowest terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let frac = Fraction::new_unreduced(12, 8)?;
+    /// let reduced = frac.reduce();
+    /// assert_eq!(reduced.to_string(), "3/2");
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn reduce(&self) -> Self {
+        let gcd = gcd(self.numerator, self.denominator);
+        Self {
+            numerator: self.numerator / gcd,
+            denominator: self.denominator / gcd,
+        }
+    }
+
+    /// Reports whether the fraction can be reduced and the gcd it would be
+    /// reduced by, without reducing it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::new_unreduced(12, 8)?.reduction_info(), (true, 4));
+    /// assert_eq!(Fraction::new(3, 2)?.reduction_info(), (false, 1));
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn reduction_info(&self) -> (bool, i64) {
+        let gcd = gcd(self.numerator, self.denominator);
+        (gcd > 1, gcd)
+    }
+
+    /// Reduces the fraction and moves the sign to the numerator.
+    fn normalized(&self) -> Self {
+        let reduced = self.reduce();
+        if reduced.denominator < 0 {
+            Self {
+                numerator: -reduced.numerator,
+                denominator: -reduced.denominator,
+            }
+        } else {
+            reduced
+        }
+    }
+
+    /// Converts the fraction to an integer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::NotAnInteger` if the fraction has a fractional part,
+    /// or `FractionError::Overflow` if the value does not fit in an `i64`.
+    pub fn to_integer(&self) -> Result<i64, FractionError> {
+        let quotient = self
+            .numerator
+            .checked_div(self.denominator)
+            .ok_or(FractionError::Overflow)?;
+        if self.numerator % self.denominator != 0 {
+            return Err(FractionError::NotAnInteger);
+        }
+        Ok(quotient)
+    }
+
+    /// Splits the fraction into its whole part and the remaining proper fraction.
+    ///
+    /// The whole part is truncated towards zero and the remainder carries the
+    /// sign of the fraction, so `-7/2` splits into `-3` and `-1/2`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let (whole, rest) = Fraction::new(14, 4)?.split_integer();
+    /// assert_eq!(whole, 3);
+    /// assert_eq!(rest, Fraction::new(1, 2)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn split_integer(&self) -> (i64, Self) {
+        let reduced = self.normalized();
+        let remainder = Self {
+            numerator: reduced.numerator % reduced.denominator,
+            denominator: reduced.denominator,
+        };
+        (reduced.numerator / reduced.denominator, remainder)
+    }
+
+    /// Decomposes the fraction into a whole part followed by successively finer sub-units.
+    ///
+    /// Each entry of `denominators` is the number of sub-units per unit of the
+    /// previous level, e.g. `[12, 16]` turns feet into feet, inches and sixteenths
+    /// of an inch. The result has one more element than `denominators`. Any amount
+    /// below the finest sub-unit is truncated, and a negative value yields
+    /// negative components.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::InvalidArgument` if a denominator is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// // 2 feet, 3 and 3/16 inches
+    /// let length = Fraction::new(435, 192)?;
+    /// assert_eq!(length.to_mixed_radix(&[12, 16])?, vec![2, 3, 3]);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn to_mixed_radix(&self, denominators: &[i64]) -> Result<Vec<i64>, FractionError> {
+        if denominators.iter().any(|&d| d <= 0) {
+            return Err(FractionError::InvalidArgument);
+        }
+
+        let value = self.normalized();
+        let sign = value.numerator.signum();
+        let den = value.denominator as i128;
+        let num = (value.numerator as i128).abs();
+
+        let mut parts = Vec::with_capacity(denominators.len() + 1);
+        parts.push(sign * (num / den) as i64);
+        let mut rem = num % den;
+        for &radix in denominators {
+            rem *= radix as i128;
+            parts.push(sign * (rem / den) as i64);
+            rem %= den;
+        }
+        Ok(parts)
+    }
+
+    /// Returns the smallest positive integer `k` such that `self * k` is an integer.
+    ///
+    /// This is the denominator of the fraction in lowest terms.
+    pub fn integerizing_factor(&self) -> i64 {
+        self.normalized().denominator
+    }
+
+    /// Returns the integer `self * k`, where `k` is the
+    /// [integerizing factor](Self::integerizing_factor).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let frac = Fraction::new(6, 8)?;
+    /// assert_eq!(frac.integerizing_factor(), 4);
+    /// assert_eq!(frac.scale_to_integer(), 3);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn scale_to_integer(&self) -> i64 {
+        self.normalized().numerator
+    }
+
+    /// Splits the fraction into `parts` equal pieces of `self / parts` that sum
+    /// exactly to `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::InvalidArgument` if `parts` is zero, or
+    /// `FractionError::Overflow` if a piece does not fit in `i64` terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let pieces = Fraction::new(3, 4)?.split_equally(3)?;
+    /// assert_eq!(pieces, vec![Fraction::new(1, 4)?; 3]);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn split_equally(&self, parts: u32) -> Result<Vec<Self>, FractionError> {
+        if parts == 0 {
+            return Err(FractionError::InvalidArgument);
+        }
+        let piece = self.checked_div(&Self::from(parts))?;
+        Ok(vec![piece; parts as usize])
+    }
+
+    /// Distributes the fraction into `parts` multiples of `1/unit_den`, like
+    /// splitting an amount into cents.
+    ///
+    /// The total is first rounded to the nearest multiple of `1/unit_den`
+    /// (halfway cases away from zero). Each part gets the floor of an equal
+    /// share and the leftover units go one each to the first parts, so the
+    /// parts always sum exactly to the rounded total.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::InvalidArgument` if `parts` is zero or
+    /// `unit_den` is not positive, or `FractionError::Overflow` if the number
+    /// of units does not fit in `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let shares = Fraction::from_integer(1).distribute(3, 100)?;
+    /// assert_eq!(shares, vec![Fraction::new(34, 100)?, Fraction::new(33, 100)?, Fraction::new(33, 100)?]);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn distribute(&self, parts: u32, unit_den: i64) -> Result<Vec<Self>, FractionError> {
+        if parts == 0 || unit_den <= 0 {
+            return Err(FractionError::InvalidArgument);
+        }
+
+        let units = div_round(
+            self.numerator as i128 * unit_den as i128,
+            self.denominator as i128,
+            RoundingMode::HalfAwayFromZero,
+        );
+        let units = i64::try_from(units).map_err(|_| FractionError::Overflow)? as i128;
+        let base = units.div_euclid(parts as i128);
+        let leftover = units.rem_euclid(parts as i128);
+
+        (0..parts as i128)
+            .map(|i| {
+                let share = if i < leftover { base + 1 } else { base };
+                Self::from_i128(share, unit_den as i128)
+            })
+            .collect()
+    }
+
+    /// Returns the unit fraction `1/n` nearest in value to a positive fraction.
+    ///
+    /// The candidates are `1/floor(1/self)` and `1/ceil(1/self)`; when both are
+    /// equally close the smaller unit fraction (larger `n`) is chosen. Values of
+    /// 1 and above give `1/1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FractionError::InvalidArgument`] if the fraction is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let frac = Fraction::new(3, 10)?;
+    /// assert_eq!(frac.nearest_unit_fraction()?, Fraction::new(1, 3)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn nearest_unit_fraction(&self) -> Result<Self, FractionError> {
+        let value = self.normalized();
+        if value.numerator <= 0 {
+            return Err(FractionError::InvalidArgument);
+        }
+
+        let (a, b) = (value.numerator as i128, value.denominator as i128);
+        if a >= b {
+            return Ok(Self::ONE);
+        }
+
+        // 1/k >= a/b > 1/(k + 1); compare (1/k - a/b) with (a/b - 1/(k + 1))
+        let k = b / a;
+        let above = (b - k * a) * (k + 1);
+        let below = ((k + 1) * a - b) * k;
+        let n = if above < below { k } else { k + 1 };
+        Self::from_i128(1, n)
+    }
+
+    /// Returns the two parents in the Stern–Brocot (Farey) tree whose mediant is this fraction.
+    ///
+    /// The parents `p/q < r/s` satisfy `a*q - b*p = 1` for the reduced fraction `a/b`,
+    /// taking `0/1` as the left bound of the tree, so `1/n` has the parent `0/1`.
+    /// Returns `None` for values that are not positive and for integers: `1/1` is
+    /// the root, and every larger integer has the right bound `1/0` as a parent,
+    /// which is not a valid fraction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let (left, right) = Fraction::new(2, 5)?.farey_parents().unwrap();
+    /// assert_eq!(left, Fraction::new(1, 3)?);
+    /// assert_eq!(right, Fraction::new(1, 2)?);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn farey_parents(&self) -> Option<(Self, Self)> {
+        let reduced = self.normalized();
+        let (a, b) = (reduced.numerator, reduced.denominator);
+        if a <= 0 || b == 1 {
+            return None;
+        }
+
+        // Extended Euclid: find q with a*q ≡ 1 (mod b)
+        let (mut old_r, mut r) = (a as i128, b as i128);
+        let (mut old_s, mut s) = (1i128, 0i128);
+        while r != 0 {
+            let quotient = old_r / r;
+            (old_r, r) = (r, old_r - quotient * r);
+            (old_s, s) = (s, old_s - quotient * s);
+        }
+        let q = old_s.rem_euclid(b as i128);
+        let p = (a as i128 * q - 1) / b as i128;
+
+        let (p, q) = (p as i64, q as i64);
+        let left = Self {
+            numerator: p,
+            denominator: q,
+        };
+        let right = Self {
+            numerator: a - p,
+            denominator: b - q,
+        };
+        Some((left, right))
+    }
+
+    /// Returns the depth of the fraction in the Stern–Brocot tree, with `1/1` at depth zero.
+    ///
+    /// The depth equals the sum of the continued-fraction coefficients minus one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::InvalidArgument` if the fraction is not positive,
+    /// or `FractionError::Overflow` if the depth does not fit in a `u32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// assert_eq!(Fraction::from_integer(1).stern_brocot_depth()?, 0);
+    /// assert_eq!(Fraction::new(2, 3)?.stern_brocot_depth()?, 2);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn stern_brocot_depth(&self) -> Result<u32, FractionError> {
+        if !self.normalized().is_positive() {
+            return Err(FractionError::InvalidArgument);
+        }
+
+        let steps: i128 = self
+            .to_continued_fraction()
+            .into_iter()
+            .map(i128::from)
+            .sum();
+        u32::try_from(steps - 1).map_err(|_| FractionError::Overflow)
+    }
+
+    /// Expands the fraction into continued-fraction coefficients `[a0; a1, a2, ...]`
+    /// with Euclid's algorithm.
+    ///
+    /// The leading coefficient is the floor of the value and carries its sign;
+    /// all later coefficients are positive. The expansion always terminates and
+    /// round-trips through [`Fraction::from_continued_fraction`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let frac = Fraction::new(-7, 3)?;
+    /// assert_eq!(frac.to_continued_fraction(), vec![-3, 1, 2]);
+    /// assert_eq!(Fraction::from_continued_fraction(&frac.to_continued_fraction())?, frac);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn to_continued_fraction(&self) -> Vec<i64> {
+        let reduced = self.normalized();
+        let (mut n, mut d) = (reduced.numerator as i128, reduced.denominator as i128);
+        let mut terms = Vec::new();
+        while d != 0 {
+            let a = n.div_euclid(d);
+            terms.push(a as i64);
+            (n, d) = (d, n - a * d);
+        }
+        terms
+    }
+
+    /// Returns the exact distance `|self - c_k|` to the `k`-th convergent of the
+    /// fraction's continued fraction, counting the integer part as convergent `0`.
+    ///
+    /// The final convergent is the fraction itself, so its error is zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::InvalidArgument` if `k` is not less than the
+    /// number of continued-fraction coefficients, or `FractionError::Overflow`
+    /// if the error does not fit in `i64` terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// // 13/8 = [1; 1, 1, 1, 2], whose second convergent is 2/1
+    /// let frac = Fraction::new(13, 8)?;
+    /// assert_eq!(frac.convergent_error(1)?, Fraction::new(3, 8)?);
+    /// assert_eq!(frac.convergent_error(4)?, Fraction::from_integer(0));
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn convergent_error(&self, k: usize) -> Result<Self, FractionError> {
+        let terms = self.to_continued_fraction();
+        if k >= terms.len() {
+            return Err(FractionError::InvalidArgument);
+        }
+        let convergent = Self::from_continued_fraction(&terms[..=k])?;
+        Ok(self.checked_sub(&convergent)?.abs())
+    }
+
+    /// Returns the positive divisors of an integer-valued fraction in ascending order.
+    ///
+    /// Zero has no finite set of divisors, so an empty vector is returned for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FractionError::NotAnInteger` if the fraction has a fractional part,
+    /// or `FractionError::Overflow` if a divisor does not fit in an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractions::Fraction;
+    ///
+    /// let twelve = Fraction::from_integer(12);
+    /// assert_eq!(twelve.integer_divisors()?, vec![1, 2, 3, 4, 6, 12]);
+    /// # Ok::<(), fractions::FractionError>(())
+    /// ```
+    pub fn integer_divisors(&self) -> Result<Vec<i64>, FractionError> {
+        let n = self.to_integer()?.unsigned_abs();
+
+        let mut small = Vec::new();
+        let mut large = Vec::new();
+        let mut d = 1;
+        while d <= n / d {
+            if n % d == 0 {
+                small.push(d);
+                if d != n / d {
+                    large.push(n / d);
+                }
+            }
+            d += 1;
+        }
+
+        small
+            .into_iter()
+            .chain(large.into_iter().rev())
+            .map(|d| i64::try_from(d).map_err(|_| FractionError::Overflow))
+            .collect()
+    }
+}
+
+/// Calculates the greatest common divisor using Euclid's algorithm.
+///
+/// The result is never negative, and `gcd(0, 0)` is `0`.
+///
+/// # Panics
+///
+/// Panics if the result is `2^63`, which happens exactly when one argument is
+/// `i64::MIN` and the other is `0` or `i64::MIN`.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::gcd;
+///
+/// assert_eq!(gcd(-12, 18), 6);
+/// assert_eq!(gcd(0, -5), 5);
+/// ```
+pub fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        let temp = b;
+        b = a % b;
+        a = temp;
+    }
+    i64::try_from(a).expect("gcd does not fit in i64")
+}
+
+/// Calculates the least common multiple, which is never negative.
+///
+/// `lcm(0, x)` is `0`. The arguments are divided by their gcd before
+/// multiplying, so only a result that itself exceeds `i64` overflows.
+///
+/// # Panics
+///
+/// Panics if the result does not fit in an `i64`; see [`checked_lcm`] for a
+/// non-panicking version.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::lcm;
+///
+/// assert_eq!(lcm(4, -6), 12);
+/// assert_eq!(lcm(0, 7), 0);
+/// ```
+pub fn lcm(a: i64, b: i64) -> i64 {
+    checked_lcm(a, b).expect("lcm does not fit in i64")
+}
+
+/// Calculates the least common multiple like [`lcm`], reporting overflow
+/// instead of panicking.
+///
+/// # Errors
+///
+/// Returns `FractionError::Overflow` if the result does not fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{checked_lcm, FractionError};
+///
+/// assert_eq!(checked_lcm(4, -6), Ok(12));
+/// assert_eq!(checked_lcm(i64::MAX, 2), Err(FractionError::Overflow));
+/// ```
+pub fn checked_lcm(a: i64, b: i64) -> Result<i64, FractionError> {
+    if a == 0 || b == 0 {
+        return Ok(0);
+    }
+    let (a, b) = (a.unsigned_abs(), b.unsigned_abs());
+    let divisor = gcd_u128(u128::from(a), u128::from(b)) as u64;
+    (a / divisor)
+        .checked_mul(b)
+        .and_then(|lcm| i64::try_from(lcm).ok())
+        .ok_or(FractionError::Overflow)
+}
+
+/// Reduces `num/den` to lowest terms with the sign on the numerator, without
+/// wrapping the result in a [`Fraction`].
+///
+/// # Errors
+///
+/// Returns `FractionError::ZeroDenominator` if `den` is zero, or
+/// `FractionError::Overflow` for `i64::MIN / -1`.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::reduce_ratio;
+///
+/// assert_eq!(reduce_ratio(12, -8)?, (-3, 2));
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn reduce_ratio(num: i64, den: i64) -> Result<(i64, i64), FractionError> {
+    let frac = Fraction::new(num, den)?;
+    Ok((frac.numerator, frac.denominator))
+}
+
+/// Builds a fraction from input that must already be canonical: in lowest
+/// terms and with a denominator of at most `max_den`.
+///
+/// A negative denominator is accepted and moved to the numerator.
+///
+/// # Errors
+///
+/// Returns `FractionError::ZeroDenominator` if `den` is zero, or
+/// `FractionError::InvalidArgument` if the terms share a common factor or the
+/// denominator's magnitude exceeds `max_den`.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{validate_reduced_bounded, Fraction, FractionError};
+///
+/// assert_eq!(validate_reduced_bounded(3, 4, 10)?, Fraction::new(3, 4)?);
+/// assert_eq!(validate_reduced_bounded(6, 8, 10), Err(FractionError::InvalidArgument));
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn validate_reduced_bounded(
+    num: i64,
+    den: i64,
+    max_den: i64,
+) -> Result<Fraction, FractionError> {
+    let frac = Fraction::new_unreduced(num, den)?;
+    let (reducible, _) = frac.reduction_info();
+    if reducible || frac.denominator > max_den {
+        return Err(FractionError::InvalidArgument);
+    }
+    Ok(frac)
+}
+
+/// Most digits written after the point by [`Fraction::to_decimal_string`].
+const DECIMAL_DIGIT_LIMIT: usize = 4096;
+
+/// Fixed-point scale used when summing series for mathematical constants.
+const CONSTANT_SCALE: i128 = 10i128.pow(36);
+
+/// Splits a finite `f64` into its sign, integer mantissa and binary exponent.
+fn decode_f64(value: f64) -> (bool, u64, i32) {
+    let bits = value.to_bits();
+    let negative = bits >> 63 == 1;
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+
+    if biased_exponent == 0 {
+        // Subnormal: no implicit leading bit
+        (negative, fraction, -1074)
+    } else {
+        (negative, fraction | (1 << 52), biased_exponent - 1075)
+    }
+}
+
+/// Multiplies two `u128` values into a `(high, low)` pair of 128-bit words.
+fn wide_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+
+    let cross = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+    let low = (cross << 64) | (lo_lo & MASK);
+    let high = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (cross >> 64);
+    (high, low)
+}
+
+/// Divides `num / den` to `places` decimal digits, rounding half away from zero.
+///
+/// Returns the whole part and the fractional digits.
+fn round_decimal(num: u128, den: u128, places: usize) -> (u128, Vec<u8>) {
+    let mut whole = num / den;
+    let mut rem = num % den;
+    let mut digits = Vec::with_capacity(places);
+    for _ in 0..places {
+        rem *= 10;
+        digits.push((rem / den) as u8);
+        rem %= den;
+    }
+
+    if 2 * rem >= den {
+        // Propagate the carry through trailing nines into the whole part
+        let mut carry = true;
+        for digit in digits.iter_mut().rev() {
+            if *digit == 9 {
+                *digit = 0;
+            } else {
+                *digit += 1;
+                carry = false;
+                break;
+            }
+        }
+        if carry {
+            whole += 1;
+        }
+    }
+
+    (whole, digits)
+}
+
+/// Appends `whole.digits` to `out`, omitting the point when there are no digits.
+fn push_decimal(out: &mut String, whole: u128, digits: &[u8]) {
+    out.push_str(&whole.to_string());
+    if !digits.is_empty() {
+        out.push('.');
+        out.extend(digits.iter().map(|&d| char::from(b'0' + d)));
+    }
+}
+
+/// Scales the positive ratio `num / den` into `[1, 10)`.
+///
+/// Returns the decimal exponent along with the scaled numerator and denominator.
+fn normalize_decimal(mut num: u128, mut den: u128) -> (i32, u128, u128) {
+    let mut exponent = 0;
+    while num >= den * 10 {
+        den *= 10;
+        exponent += 1;
+    }
+    while num < den {
+        num *= 10;
+        exponent -= 1;
+    }
+    (exponent, num, den)
+}
+
+/// Divides `num / den` rounding to an integer according to `mode`.
+fn div_round(num: i128, den: i128, mode: RoundingMode) -> i128 {
+    let (num, den) = if den < 0 { (-num, -den) } else { (num, den) };
+    let floor = num.div_euclid(den);
+    let rem = num.rem_euclid(den);
+    if rem == 0 {
+        return floor;
+    }
+
+    let ceil = floor + 1;
+    let (toward_zero, away_from_zero) = if num < 0 {
+        (ceil, floor)
+    } else {
+        (floor, ceil)
+    };
+    let half = (2 * rem).cmp(&den);
+
+    match mode {
+        RoundingMode::Floor => floor,
+        RoundingMode::Ceiling => ceil,
+        RoundingMode::TowardZero => toward_zero,
+        RoundingMode::AwayFromZero => away_from_zero,
+        _ if half == Ordering::Less => floor,
+        _ if half == Ordering::Greater => ceil,
+        RoundingMode::HalfUp => ceil,
+        RoundingMode::HalfDown => floor,
+        RoundingMode::HalfTowardZero => toward_zero,
+        RoundingMode::HalfAwayFromZero => away_from_zero,
+        RoundingMode::HalfToEven => {
+            if floor % 2 == 0 {
+                floor
+            } else {
+                ceil
+            }
+        }
+        RoundingMode::HalfToOdd => {
+            if floor % 2 == 0 {
+                ceil
+            } else {
+                floor
+            }
+        }
+    }
+}
+
+/// Returns how many times `factor` divides `n`.
+fn count_factor(mut n: u128, factor: u128) -> u32 {
+    let mut count = 0;
+    while n != 0 && n.is_multiple_of(factor) {
+        n /= factor;
+        count += 1;
+    }
+    count
+}
+
+/// Calculates the greatest common divisor of wide unsigned values.
+fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let temp = b;
+        b = a % b;
+        a = temp;
+    }
+    a
+}
+
+/// Returns the GCD of the numerators once all fractions are reduced and
+/// brought to their least common denominator. Returns `0` for empty input.
+///
+/// Scaling reduced fractions `a_i/b_i` to the common denominator `L` gives the
+/// numerators `a_i * (L / b_i)`, whose GCD equals the GCD of the `a_i` since
+/// `L` is the LCM of the `b_i`. The common denominator therefore never has to be
+/// formed.
+///
+/// # Errors
+///
+/// Returns `FractionError::Overflow` if the GCD is `2^63`, which happens when
+/// every nonzero numerator is `i64::MIN`.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{numerators_gcd, Fraction};
+///
+/// // Over the common denominator 15: 10/15 and 12/15
+/// let values = [Fraction::new(2, 3)?, Fraction::new(4, 5)?];
+/// assert_eq!(numerators_gcd(&values)?, 2);
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn numerators_gcd(fractions: &[Fraction]) -> Result<i64, FractionError> {
+    let divisor = fractions.iter().fold(0, |acc, frac| {
+        gcd_u128(acc, u128::from(frac.reduce().numerator.unsigned_abs()))
+    });
+    i64::try_from(divisor).map_err(|_| FractionError::Overflow)
+}
+
+/// Reduces every fraction in the slice to lowest terms in place.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{reduce_all, Fraction};
+///
+/// let mut values = [Fraction::new(2, 4)?, Fraction::new(9, 6)?];
+/// reduce_all(&mut values);
+/// assert_eq!(values[1].denominator(), 2);
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn reduce_all(slice: &mut [Fraction]) {
+    for frac in slice.iter_mut() {
+        *frac = frac.reduce();
+    }
+}
+
+/// Returns the least common multiple of the reduced denominators.
+///
+/// This is the smallest denominator over which every fraction in the slice
+/// can be written exactly. An empty slice yields `1`.
+///
+/// # Errors
+///
+/// Returns `FractionError::Overflow` if the common denominator does not fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{common_base, Fraction};
+///
+/// let rates = [Fraction::new(1, 2)?, Fraction::new(1, 3)?, Fraction::new(1, 4)?];
+/// assert_eq!(common_base(&rates)?, 12);
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn common_base(fractions: &[Fraction]) -> Result<i64, FractionError> {
+    fractions.iter().try_fold(1i64, |acc, frac| {
+        let den = frac.reduce().denominator.abs();
+        (acc / gcd(acc, den))
+            .checked_mul(den)
+            .ok_or(FractionError::Overflow)
+    })
+}
+
+/// Removes fractions that are equal in value to an earlier element, keeping
+/// the first occurrence of each value in its original position.
+///
+/// Unlike `Vec::dedup`, duplicates need not be adjacent, and different
+/// representations of the same value such as `1/2` and `2/4` collapse.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{dedup_fractions, Fraction};
+///
+/// let mut values = vec![Fraction::new(1, 2)?, Fraction::new(1, 3)?, Fraction::new(2, 4)?];
+/// dedup_fractions(&mut values);
+/// assert_eq!(values.len(), 2);
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn dedup_fractions(fractions: &mut Vec<Fraction>) {
+    let mut seen = HashSet::new();
+    fractions.retain(|frac| {
+        let canonical = frac.normalized();
+        seen.insert((canonical.numerator, canonical.denominator))
+    });
+}
+
+/// Returns an iterator over the successive powers `1, base, base^2, ...` of a fraction.
+///
+/// Each power is reduced to lowest terms. The iterator ends, rather than
+/// panicking, once the next power no longer fits in `i64` terms.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{powers_of, Fraction};
+///
+/// let scales: Vec<_> = powers_of(Fraction::new(1, 2)?).take(3).collect();
+/// assert_eq!(scales[2], Fraction::new(1, 4)?);
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn powers_of(base: Fraction) -> impl Iterator<Item = Fraction> {
+    std::iter::successors(Some(Fraction::from_integer(1)), move |power| {
+        power.checked_mul(&base).ok()
+    })
+}
+
+/// Returns the index of the first element of a sorted slice that is `>= target`.
+///
+/// Comparisons use [`Fraction::safe_cmp`], so large terms cannot overflow. If
+/// every element is smaller than `target`, the slice length is returned.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{lower_bound, Fraction};
+///
+/// let values = [Fraction::new(1, 4)?, Fraction::new(1, 2)?, Fraction::new(3, 4)?];
+/// assert_eq!(lower_bound(&values, &Fraction::new(2, 4)?), 1);
+/// assert_eq!(lower_bound(&values, &Fraction::new(5, 8)?), 2);
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn lower_bound(slice: &[Fraction], target: &Fraction) -> usize {
+    slice.partition_point(|value| value.safe_cmp(target) == Ordering::Less)
+}
+
+/// Counts how many values fall into each half-open bin `[edges[i], edges[i + 1])`.
+///
+/// Values below the first edge or at or above the last edge are ignored.
+/// Comparisons use [`Fraction::safe_cmp`], so large terms cannot overflow.
+///
+/// # Errors
+///
+/// Returns `FractionError::InvalidArgument` if there are fewer than two edges
+/// or the edges are not strictly increasing.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{histogram, Fraction};
+///
+/// let values = [Fraction::new(1, 4)?, Fraction::new(1, 2)?, Fraction::new(3, 4)?];
+/// let edges = [Fraction::from_integer(0), Fraction::new(1, 2)?, Fraction::from_integer(1)];
+/// assert_eq!(histogram(&values, &edges)?, vec![1, 2]);
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn histogram(values: &[Fraction], edges: &[Fraction]) -> Result<Vec<usize>, FractionError> {
+    if edges.len() < 2
+        || edges
+            .windows(2)
+            .any(|pair| pair[0].safe_cmp(&pair[1]) != Ordering::Less)
+    {
+        return Err(FractionError::InvalidArgument);
+    }
+
+    let mut counts = vec![0; edges.len() - 1];
+    for value in values {
+        let above = edges.partition_point(|edge| edge.safe_cmp(value) != Ordering::Greater);
+        if (1..edges.len()).contains(&above) {
+            counts[above - 1] += 1;
+        }
+    }
+    Ok(counts)
+}
+
+/// Returns the largest value seen so far at each index of the slice.
+///
+/// Comparisons use [`Fraction::safe_cmp`], so large terms cannot overflow.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{running_max, Fraction};
+///
+/// let values = [Fraction::new(1, 3)?, Fraction::new(1, 2)?, Fraction::new(1, 4)?];
+/// assert_eq!(running_max(&values)[2], Fraction::new(1, 2)?);
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn running_max(fractions: &[Fraction]) -> Vec<Fraction> {
+    running_extreme(fractions, Ordering::Greater)
+}
+
+/// Returns the smallest value seen so far at each index of the slice.
+///
+/// Comparisons use [`Fraction::safe_cmp`], so large terms cannot overflow.
+pub fn running_min(fractions: &[Fraction]) -> Vec<Fraction> {
+    running_extreme(fractions, Ordering::Less)
+}
+
+/// Scans the slice, replacing the current extreme whenever a value compares as `replace_on`.
+fn running_extreme(fractions: &[Fraction], replace_on: Ordering) -> Vec<Fraction> {
+    let mut extremes: Vec<Fraction> = Vec::with_capacity(fractions.len());
+    for frac in fractions {
+        let next = match extremes.last() {
+            Some(current) if frac.safe_cmp(current) != replace_on => *current,
+            _ => *frac,
+        };
+        extremes.push(next);
+    }
+    extremes
+}
+
+/// Splits every fraction into its whole part and proper remainder.
+///
+/// See [`Fraction::split_integer`] for the sign convention.
+pub fn split_integers(fractions: &[Fraction]) -> Vec<(i64, Fraction)> {
+    fractions.iter().map(Fraction::split_integer).collect()
+}
+
+/// Extension trait adding [`reduced`](ReduceExt::reduced) to iterators of fractions.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{Fraction, ReduceExt};
+///
+/// let values = vec![Fraction::new(2, 4)?, Fraction::new(6, 3)?];
+/// let terms: Vec<_> = values
+///     .into_iter()
+///     .reduced()
+///     .map(|f| (f.numerator(), f.denominator()))
+///     .collect();
+/// assert_eq!(terms, vec![(1, 2), (2, 1)]);
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub trait ReduceExt: Iterator<Item = Fraction> + Sized {
+    /// Lazily reduces each fraction to lowest terms.
+    fn reduced(self) -> Reduced<Self> {
+        Reduced { iter: self }
+    }
+}
+
+impl<I: Iterator<Item = Fraction>> ReduceExt for I {}
+
+/// Iterator adapter returned by [`ReduceExt::reduced`].
+#[derive(Debug, Clone)]
+pub struct Reduced<I> {
+    iter: I,
+}
+
+impl<I: Iterator<Item = Fraction>> Iterator for Reduced<I> {
+    type Item = Fraction;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|frac| frac.reduce())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Interactive navigation of the Stern–Brocot tree of positive fractions.
+///
+/// The search keeps the bounding fractions `left` and `right`, starting from
+/// `0/1` and the formal `1/0`, and the current node is always their mediant.
+/// Moving left or right narrows the interval, so any positive fraction is
+/// reached by a unique sequence of moves. Every node is in lowest terms.
+///
+/// # Panics
+///
+/// Moving and reading the current node panic if a mediant's terms overflow `i64`.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{Fraction, SternBrocotSearch};
+///
+/// let mut search = SternBrocotSearch::new();
+/// assert_eq!(search.current(), Fraction::from_integer(1));
+/// search.go_left();
+/// search.go_right();
+/// assert_eq!(search.current(), Fraction::new(2, 3)?);
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SternBrocotSearch {
+    left: (i64, i64),
+    right: (i64, i64),
+}
+
+impl SternBrocotSearch {
+    /// Starts a search at the root `1/1`.
+    pub fn new() -> Self {
+        Self {
+            left: (0, 1),
+            right: (1, 0),
+        }
+    }
+
+    /// Returns the current node, the mediant of the bounding fractions.
+    pub fn current(&self) -> Fraction {
+        let (numerator, denominator) = self.mediant();
+        Fraction {
+            numerator,
+            denominator,
+        }
+    }
+
+    /// Moves to the left child, whose value is smaller than the current node.
+    pub fn go_left(&mut self) {
+        self.right = self.mediant();
+    }
+
+    /// Moves to the right child, whose value is larger than the current node.
+    pub fn go_right(&mut self) {
+        self.left = self.mediant();
+    }
+
+    fn mediant(&self) -> (i64, i64) {
+        (self.left.0 + self.right.0, self.left.1 + self.right.1)
+    }
+}
+
+impl Default for SternBrocotSearch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the weighted median of `(value, weight)` pairs.
+///
+/// The median is the value at which the cumulative weight, in ascending
+/// order of value, first reaches half the total. When the cumulative weight
+/// lands exactly on the half, the result is the average of that value and the
+/// next one. Items with zero weight are ignored.
+///
+/// Returns `None` for empty input, a zero total weight, any negative weight,
+/// or an average that overflows `i64` terms.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{weighted_median, Fraction};
+///
+/// let items = [
+///     (Fraction::new(1, 2)?, 1),
+///     (Fraction::new(1, 3)?, 3),
+///     (Fraction::new(3, 4)?, 1),
+/// ];
+/// assert_eq!(weighted_median(&items), Some(Fraction::new(1, 3)?));
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn weighted_median(items: &[(Fraction, i64)]) -> Option<Fraction> {
+    if items.iter().any(|&(_, weight)| weight < 0) {
+        return None;
+    }
+
+    let mut sorted: Vec<_> = items.iter().filter(|&&(_, weight)| weight > 0).collect();
+    sorted.sort_by(|a, b| a.0.safe_cmp(&b.0));
+
+    let total: i128 = sorted.iter().map(|&&(_, weight)| weight as i128).sum();
+    if total == 0 {
+        return None;
+    }
+
+    let mut cumulative = 0i128;
+    for (i, &&(value, weight)) in sorted.iter().enumerate() {
+        cumulative += weight as i128;
+        match (2 * cumulative).cmp(&total) {
+            Ordering::Less => continue,
+            Ordering::Equal => {
+                // (a/b + c/d) / 2 in i128, so only an unrepresentable average fails
+                let next = sorted[i + 1].0;
+                let (a, b) = (value.numerator as i128, value.denominator as i128);
+                let (c, d) = (next.numerator as i128, next.denominator as i128);
+                return Fraction::from_i128(a * d + c * b, 2 * b * d).ok();
+            }
+            Ordering::Greater => return Some(value.reduce()),
+        }
+    }
+    None
+}
+
+/// Checks whether `a`, `b`, `c` form an arithmetic progression, i.e. `b - a == c - b`.
+///
+/// The differences are compared exactly in `i128`, so this never overflows.
+pub fn is_arithmetic_progression(a: &Fraction, b: &Fraction, c: &Fraction) -> bool {
+    wide_difference(a, b) == wide_difference(b, c)
+}
+
+/// Returns `to - from` as an `i128` pair in lowest terms with a positive denominator.
+fn wide_difference(from: &Fraction, to: &Fraction) -> (i128, i128) {
+    let numerator = to.numerator as i128 * from.denominator as i128
+        - from.numerator as i128 * to.denominator as i128;
+    let denominator = to.denominator as i128 * from.denominator as i128;
+    wide_reduced(numerator, denominator)
+}
+
+/// Checks whether consecutive elements of the slice all differ by the same amount.
+///
+/// Slices with fewer than three elements are trivially arithmetic.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{is_arithmetic_sequence, Fraction};
+///
+/// let quarters = [Fraction::new(1, 4)?, Fraction::new(1, 2)?, Fraction::new(3, 4)?];
+/// assert!(is_arithmetic_sequence(&quarters));
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn is_arithmetic_sequence(fractions: &[Fraction]) -> bool {
+    fractions
+        .windows(3)
+        .all(|w| is_arithmetic_progression(&w[0], &w[1], &w[2]))
+}
+
+/// Checks whether consecutive elements of the slice all share the same ratio.
+///
+/// Ratios are compared exactly by cross-multiplication (`b * b == a * c`) in
+/// `i128`, so no division takes place and nothing overflows. Any zero interior
+/// term leaves the ratio undefined and makes the check fail. Slices with fewer
+/// than three elements are trivially geometric.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{is_geometric_sequence, Fraction};
+///
+/// let halves = [Fraction::from_integer(1), Fraction::new(1, 2)?, Fraction::new(1, 4)?];
+/// assert!(is_geometric_sequence(&halves));
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn is_geometric_sequence(fractions: &[Fraction]) -> bool {
+    fractions
+        .windows(3)
+        .all(|w| !w[1].is_zero() && wide_product(&w[1], &w[1]) == wide_product(&w[0], &w[2]))
+}
+
+/// Returns `a * b` as an `i128` pair in lowest terms with a positive denominator.
+fn wide_product(a: &Fraction, b: &Fraction) -> (i128, i128) {
+    let numerator = a.numerator as i128 * b.numerator as i128;
+    let denominator = a.denominator as i128 * b.denominator as i128;
+    wide_reduced(numerator, denominator)
+}
+
+/// Reduces an `i128` pair to lowest terms with a positive denominator.
+fn wide_reduced(numerator: i128, denominator: i128) -> (i128, i128) {
+    let divisor = gcd_u128(numerator.unsigned_abs(), denominator.unsigned_abs()) as i128;
+    let (numerator, denominator) = (numerator / divisor, denominator / divisor);
+    if denominator < 0 {
+        (-numerator, -denominator)
+    } else {
+        (numerator, denominator)
+    }
+}
+
+/// Returns the expected value `sum(probability * value)` of a discrete distribution.
+///
+/// Each outcome is a `(probability, value)` pair. The probabilities are not
+/// checked; use [`validate_distribution`] for that. The running total is
+/// reduced after every term.
+///
+/// # Errors
+///
+/// Returns `FractionError::Overflow` if an intermediate result does not fit in `i64` terms.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{expected_value, Fraction};
+///
+/// let coin = [
+///     (Fraction::new(1, 2)?, Fraction::from_integer(10)),
+///     (Fraction::new(1, 2)?, Fraction::from_integer(-4)),
+/// ];
+/// assert_eq!(expected_value(&coin)?, Fraction::from_integer(3));
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn expected_value(outcomes: &[(Fraction, Fraction)]) -> Result<Fraction, FractionError> {
+    outcomes
+        .iter()
+        .try_fold(Fraction::from_integer(0), |total, (probability, value)| {
+            total.checked_add(&probability.checked_mul(value)?)
+        })
+}
+
+/// Sums the fractions like the [`Sum`] impl, reporting overflow instead of panicking.
+///
+/// An empty slice sums to `0/1`.
+///
+/// # Errors
+///
+/// Returns `FractionError::Overflow` if a partial sum does not fit in `i64` terms.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{checked_sum, Fraction};
+///
+/// let parts = [Fraction::new(1, 2)?, Fraction::new(1, 3)?, Fraction::new(1, 6)?];
+/// assert_eq!(checked_sum(&parts)?, Fraction::from_integer(1));
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn checked_sum(fractions: &[Fraction]) -> Result<Fraction, FractionError> {
+    fractions
+        .iter()
+        .try_fold(Fraction::from_integer(0), |total, frac| {
+            total.checked_add(frac)
+        })
+}
+
+/// Multiplies the fractions like the [`Product`] impl, reporting overflow
+/// instead of panicking.
+///
+/// An empty slice gives `1/1`.
+///
+/// # Errors
+///
+/// Returns `FractionError::Overflow` if a partial product does not fit in `i64` terms.
+pub fn checked_product(fractions: &[Fraction]) -> Result<Fraction, FractionError> {
+    fractions
+        .iter()
+        .try_fold(Fraction::from_integer(1), |total, frac| {
+            total.checked_mul(frac)
+        })
+}
+
+/// Checks that every probability lies in `[0, 1]` and that they sum to exactly one.
+///
+/// # Errors
+///
+/// Returns `FractionError::InvalidArgument` if the probabilities do not form a
+/// distribution, or `FractionError::Overflow` if their sum does not fit in `i64` terms.
+pub fn validate_distribution(outcomes: &[(Fraction, Fraction)]) -> Result<(), FractionError> {
+    let zero = Fraction::from_integer(0);
+    let one = Fraction::from_integer(1);
+
+    let mut total = zero;
+    for (probability, _) in outcomes {
+        if probability.safe_cmp(&zero) == Ordering::Less
+            || probability.safe_cmp(&one) == Ordering::Greater
+        {
+            return Err(FractionError::InvalidArgument);
+        }
+        total = total.checked_add(probability)?;
+    }
+
+    if total.safe_cmp(&one) == Ordering::Equal {
+        Ok(())
+    } else {
+        Err(FractionError::InvalidArgument)
+    }
+}
+
+/// Combines values like resistors in parallel, computing `1 / sum(1 / x_i)` exactly.
+///
+/// # Errors
+///
+/// Returns `FractionError::DivisionByZero` if any element is zero or the
+/// reciprocals sum to zero (including for an empty slice), or
+/// `FractionError::Overflow` if an intermediate result does not fit in `i64` terms.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{parallel_combine, Fraction};
+///
+/// let resistors = [Fraction::from_integer(2), Fraction::from_integer(2)];
+/// assert_eq!(parallel_combine(&resistors)?, Fraction::from_integer(1));
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn parallel_combine(fractions: &[Fraction]) -> Result<Fraction, FractionError> {
+    fractions
+        .iter()
+        .try_fold(Fraction::from_integer(0), |total, frac| {
+            total.checked_add(&frac.reciprocal()?)
+        })?
+        .reciprocal()
+}
+
+/// Returns `sum(C(n, k) * values[k])` where `n = values.len() - 1`.
+///
+/// The binomial coefficients are computed exactly row by row, and the total is
+/// reduced after every term. An empty slice yields zero.
+///
+/// # Errors
+///
+/// Returns `FractionError::Overflow` if a coefficient or intermediate result
+/// does not fit in `i64` terms.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{binomial_weighted_sum, Fraction};
+///
+/// // 1*(1/2) + 2*(1/3) + 1*(1/4)
+/// let values = [Fraction::new(1, 2)?, Fraction::new(1, 3)?, Fraction::new(1, 4)?];
+/// assert_eq!(binomial_weighted_sum(&values)?, Fraction::new(17, 12)?);
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn binomial_weighted_sum(values: &[Fraction]) -> Result<Fraction, FractionError> {
+    let n = values.len().saturating_sub(1) as i128;
+    let mut coefficient = 1i128;
+    let mut total = Fraction::from_integer(0);
+
+    for (k, value) in values.iter().enumerate() {
+        let weight = i64::try_from(coefficient).map_err(|_| FractionError::Overflow)?;
+        total = total.checked_add(&value.checked_mul(&Fraction::from_integer(weight))?)?;
+        // C(n, k + 1) = C(n, k) * (n - k) / (k + 1), which divides exactly
+        coefficient = coefficient
+            .checked_mul(n - k as i128)
+            .ok_or(FractionError::Overflow)?
+            / (k as i128 + 1);
+    }
+    Ok(total)
+}
+
+/// Scales every element by the same factor so that their total equals `target`.
+///
+/// # Errors
+///
+/// Returns `FractionError::DivisionByZero` if the elements sum to zero
+/// (including for an empty slice), or `FractionError::Overflow` if an
+/// intermediate result does not fit in `i64` terms.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{scale_to_sum, Fraction};
+///
+/// let halves = [Fraction::new(1, 2)?, Fraction::new(1, 2)?];
+/// let scaled = scale_to_sum(&halves, &Fraction::from_integer(3))?;
+/// assert_eq!(scaled, vec![Fraction::new(3, 2)?, Fraction::new(3, 2)?]);
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn scale_to_sum(
+    fractions: &[Fraction],
+    target: &Fraction,
+) -> Result<Vec<Fraction>, FractionError> {
+    let total = fractions
+        .iter()
+        .try_fold(Fraction::from_integer(0), |total, frac| {
+            total.checked_add(frac)
+        })?;
+    let factor = target.checked_mul(&total.reciprocal()?)?;
+    fractions
+        .iter()
+        .map(|frac| frac.checked_mul(&factor))
+        .collect()
+}
+
+/// Clamps every probability to `[0, 1]` and rescales them to sum exactly to `1`.
+///
+/// The slice is left untouched if an error is returned.
+///
+/// # Errors
+///
+/// Returns `FractionError::DivisionByZero` if every element clamps to zero
+/// (including for an empty slice), or `FractionError::Overflow` if an
+/// intermediate result does not fit in `i64` terms.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{clamp_and_renormalize, Fraction};
+///
+/// let mut probs = [Fraction::new(1, 2)?, Fraction::new(3, 2)?, Fraction::new(-1, 4)?];
+/// clamp_and_renormalize(&mut probs)?;
+/// assert_eq!(probs, [Fraction::new(1, 3)?, Fraction::new(2, 3)?, Fraction::from_integer(0)]);
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn clamp_and_renormalize(probs: &mut [Fraction]) -> Result<(), FractionError> {
+    let (zero, one) = (Fraction::from_integer(0), Fraction::from_integer(1));
+    let clamped: Vec<_> = probs.iter().map(|p| (*p).clamp(zero, one)).collect();
+    let scaled = scale_to_sum(&clamped, &one)?;
+    probs.copy_from_slice(&scaled);
+    Ok(())
+}
+
+/// Returns the weighted average of `points`, `sum(p_i * w_i) / sum(w_i)`, in lowest terms.
+///
+/// # Errors
+///
+/// Returns `FractionError::InvalidArgument` if the slices differ in length,
+/// `FractionError::DivisionByZero` if the weights sum to zero (including for
+/// empty input), or `FractionError::Overflow` if an intermediate result does
+/// not fit in `i64` terms.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{blend, Fraction};
+///
+/// let points = [Fraction::from_integer(0), Fraction::from_integer(1)];
+/// let weights = [Fraction::from_integer(3), Fraction::from_integer(1)];
+/// assert_eq!(blend(&points, &weights)?, Fraction::new(1, 4)?);
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn blend(points: &[Fraction], weights: &[Fraction]) -> Result<Fraction, FractionError> {
+    if points.len() != weights.len() {
+        return Err(FractionError::InvalidArgument);
+    }
+
+    let zero = Fraction::from_integer(0);
+    let (weighted, total) = points.iter().zip(weights).try_fold(
+        (zero, zero),
+        |(weighted, total), (point, weight)| {
+            Ok::<_, FractionError>((
+                weighted.checked_add(&point.checked_mul(weight)?)?,
+                total.checked_add(weight)?,
+            ))
+        },
+    )?;
+    weighted.checked_div(&total)
+}
+
+/// Distributes `total` whole units in proportion to `shares` with the
+/// largest-remainder (Hamilton) method.
+///
+/// Every party first gets the floor of its exact quota `total * share / sum`.
+/// The units left over go one each to the parties with the largest
+/// fractional remainders, with ties going to the earlier party. The result
+/// always sums to `total`.
+///
+/// # Errors
+///
+/// Returns `FractionError::InvalidArgument` if `total` or a share is
+/// negative, `FractionError::DivisionByZero` if the shares sum to zero
+/// (including for empty input), or `FractionError::Overflow` if a quota does
+/// not fit in `i64` terms.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{apportion, Fraction};
+///
+/// let shares = [Fraction::from_integer(5), Fraction::from_integer(3), Fraction::from_integer(2)];
+/// assert_eq!(apportion(4, &shares)?, vec![2, 1, 1]);
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn apportion(total: i64, shares: &[Fraction]) -> Result<Vec<i64>, FractionError> {
+    if total < 0 || shares.iter().any(Fraction::is_negative) {
+        return Err(FractionError::InvalidArgument);
+    }
+
+    let quotas = scale_to_sum(shares, &Fraction::from_integer(total))?;
+    let mut seats: Vec<i64> = quotas.iter().map(Fraction::floor).collect();
+    let remainders: Vec<Fraction> = quotas
+        .iter()
+        .zip(&seats)
+        .map(|(quota, &floor)| quota.checked_sub(&Fraction::from_integer(floor)))
+        .collect::<Result<_, _>>()?;
+
+    let mut order: Vec<usize> = (0..shares.len()).collect();
+    order.sort_by(|&i, &j| remainders[j].cmp(&remainders[i]));
+    let leftover = total - seats.iter().sum::<i64>();
+    for &i in order.iter().take(leftover as usize) {
+        seats[i] += 1;
+    }
+    Ok(seats)
+}
+
+/// Evaluates the piecewise-linear function through `points` at `x` exactly.
+///
+/// The points are `(x, y)` pairs and must be sorted by strictly increasing `x`.
+///
+/// # Errors
+///
+/// Returns `FractionError::InvalidArgument` if `points` is empty or not strictly
+/// increasing in `x`, or if `x` lies outside the range covered by the points.
+/// Returns `FractionError::Overflow` if an intermediate result does not fit in `i64` terms.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{piecewise_linear, Fraction};
+///
+/// let table = [
+///     (Fraction::from_integer(0), Fraction::from_integer(0)),
+///     (Fraction::from_integer(2), Fraction::from_integer(1)),
+/// ];
+/// let y = piecewise_linear(&table, &Fraction::from_integer(1))?;
+/// assert_eq!(y, Fraction::new(1, 2)?);
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn piecewise_linear(
+    points: &[(Fraction, Fraction)],
+    x: &Fraction,
+) -> Result<Fraction, FractionError> {
+    if points.is_empty()
+        || points
+            .windows(2)
+            .any(|w| w[0].0.safe_cmp(&w[1].0) != Ordering::Less)
+    {
+        return Err(FractionError::InvalidArgument);
+    }
+
+    let (first_x, first_y) = points[0];
+    if x.safe_cmp(&first_x) == Ordering::Equal {
+        return Ok(first_y.reduce());
+    }
+
+    for w in points.windows(2) {
+        let ((x0, y0), (x1, y1)) = (w[0], w[1]);
+        if x.safe_cmp(&x0) == Ordering::Greater && x.safe_cmp(&x1) != Ordering::Greater {
+            let t = x
+                .checked_sub(&x0)?
+                .checked_mul(&x1.checked_sub(&x0)?.reciprocal()?)?;
+            return y0.checked_add(&y1.checked_sub(&y0)?.checked_mul(&t)?);
+        }
+    }
+    Err(FractionError::InvalidArgument)
+}
+
+/// Returns the first `terms` coefficients of the continued fraction of `sqrt(n)`.
+///
+/// The expansion `[a0; a1, a2, ...]` of a non-square integer's square root is
+/// periodic after `a0`. The coefficients are generated exactly with the
+/// standard recurrence on `(m, d, a)`, whose values stay below `2 * sqrt(n)`.
+///
+/// # Errors
+///
+/// Returns `FractionError::InvalidArgument` if `n` is negative or a perfect square.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::sqrt_continued_fraction;
+///
+/// assert_eq!(sqrt_continued_fraction(2, 4)?, vec![1, 2, 2, 2]);
+/// assert_eq!(sqrt_continued_fraction(7, 5)?, vec![2, 1, 1, 1, 4]);
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn sqrt_continued_fraction(n: i64, terms: usize) -> Result<Vec<i64>, FractionError> {
+    if n < 0 {
+        return Err(FractionError::InvalidArgument);
+    }
+    let a0 = n.isqrt();
+    if a0 * a0 == n {
+        return Err(FractionError::InvalidArgument);
+    }
+
+    let (mut m, mut d, mut a) = (0, 1, a0);
+    let mut coefficients = Vec::with_capacity(terms);
+    for _ in 0..terms {
+        coefficients.push(a);
+        m = d * a - m;
+        d = (n - m * m) / d;
+        a = (a0 + m) / d;
+    }
+    Ok(coefficients)
+}
+
+/// Solves the proportion `a/b = c/x` for `x`, returning `b * c / a` in lowest terms.
+///
+/// # Errors
+///
+/// Returns `FractionError::DivisionByZero` if `a`, `b` or `c` is zero, since
+/// the proportion then has no unique solution, or `FractionError::Overflow`
+/// if the result does not fit in `i64` terms.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{solve_proportion, Fraction};
+///
+/// let x = solve_proportion(&Fraction::from_integer(2), &Fraction::from_integer(3), &Fraction::from_integer(4))?;
+/// assert_eq!(x, Fraction::from_integer(6));
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn solve_proportion(
+    a: &Fraction,
+    b: &Fraction,
+    c: &Fraction,
+) -> Result<Fraction, FractionError> {
+    if b.is_zero() || c.is_zero() {
+        return Err(FractionError::DivisionByZero);
+    }
+    b.checked_mul(c)?.checked_div(a)
+}
+
+/// Solves the 2x2 linear system `a * x = b` exactly using Cramer's rule.
+///
+/// # Errors
+///
+/// Returns `FractionError::DivisionByZero` if the system is singular
+/// (its determinant is zero), or `FractionError::Overflow` if the determinant
+/// or an intermediate product does not fit in `i64` terms.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{solve_2x2, Fraction};
+///
+/// // x + y = 3, x - y = 1
+/// let one = Fraction::from_integer(1);
+/// let a = [[one, one], [one, -one]];
+/// let b = [Fraction::from_integer(3), one];
+/// assert_eq!(solve_2x2(a, b)?, [Fraction::from_integer(2), one]);
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+pub fn solve_2x2(a: [[Fraction; 2]; 2], b: [Fraction; 2]) -> Result<[Fraction; 2], FractionError> {
+    let det = a[0][0]
+        .checked_mul(&a[1][1])?
+        .checked_sub(&a[0][1].checked_mul(&a[1][0])?)?;
+    let x = b[0]
+        .checked_mul(&a[1][1])?
+        .checked_sub(&a[0][1].checked_mul(&b[1])?)?
+        .checked_div(&det)?;
+    let y = a[0][0]
+        .checked_mul(&b[1])?
+        .checked_sub(&b[0].checked_mul(&a[1][0])?)?
+        .checked_div(&det)?;
+    Ok([x, y])
+}
+
+impl fmt::Display for Fraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reduced = self.reduce();
+        if reduced.denominator == 1 {
+            write!(f, "{}", reduced.numerator)
+        } else {
+            write!(f, "{}/{}", reduced.numerator, reduced.denominator)
+        }
+    }
+}
+
+/// Displays a fraction in its exact form followed by a rounded decimal.
+///
+/// The second field is the number of decimal places. Rounding is exact and
+/// goes half away from zero.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::{Fraction, WithDecimal};
+///
+/// let frac = Fraction::new(3, 4)?;
+/// assert_eq!(WithDecimal(frac, 4).to_string(), "3/4 (0.7500)");
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct WithDecimal(pub Fraction, pub usize);
+
+impl fmt::Display for WithDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.0, self.0.format_decimal(self.1))
+    }
+}
+
+impl PartialEq for Fraction {
+    fn eq(&self, other: &Self) -> bool {
+        self.safe_cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Fraction {}
+
+impl PartialOrd for Fraction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Cross-multiplies in `i128`, so every pair of fractions compares correctly.
+impl Ord for Fraction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.safe_cmp(other)
+    }
+}
+
+/// Hashes the reduced, sign-normalized terms so that equal fractions such as
+/// `1/2` and `2/4` hash identically.
+impl Hash for Fraction {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let normalized = self.normalized();
+        (normalized.numerator, normalized.denominator).hash(state);
+    }
+}
+
+/// # Panics
+///
+/// Panics if the reduced sum does not fit in `i64` terms; use
+/// [`Fraction::checked_add`] to get `FractionError::Overflow` instead.
+impl Add for Fraction {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Fraction::add(&self, &other)
+    }
+}
+
+/// # Panics
+///
+/// Panics if the reduced difference does not fit in `i64` terms; use
+/// [`Fraction::checked_sub`] to get `FractionError::Overflow` instead.
+impl Sub for Fraction {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self.subtract(&other)
+    }
+}
+
+/// # Panics
+///
+/// Panics if the reduced product does not fit in `i64` terms; use
+/// [`Fraction::checked_mul`] to get `FractionError::Overflow` instead.
+impl Mul for Fraction {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.multiply(&other)
+    }
+}
+
+/// # Panics
+///
+/// Panics if the reduced quotient does not fit in `i64` terms; use
+/// [`Fraction::checked_div`] to get `FractionError::Overflow` instead.
+impl Div for Fraction {
+    type Output = Result<Self, FractionError>;
+
+    fn div(self, other: Self) -> Self::Output {
+        self.divide(&other)
+    }
+}
+
+impl Rem for Fraction {
+    type Output = Result<Self, FractionError>;
+
+    fn rem(self, other: Self) -> Self::Output {
+        Fraction::rem(&self, &other)
+    }
+}
+
+impl Add<i64> for Fraction {
+    type Output = Self;
+
+    fn add(self, other: i64) -> Self::Output {
+        self + Self::from_integer(other)
+    }
+}
+
+impl Sub<i64> for Fraction {
+    type Output = Self;
+
+    fn sub(self, other: i64) -> Self::Output {
+        self - Self::from_integer(other)
+    }
+}
+
+impl Mul<i64> for Fraction {
+    type Output = Self;
+
+    fn mul(self, other: i64) -> Self::Output {
+        self * Self::from_integer(other)
+    }
+}
+
+impl Div<i64> for Fraction {
+    type Output = Result<Self, FractionError>;
+
+    fn div(self, other: i64) -> Self::Output {
+        self / Self::from_integer(other)
+    }
+}
+
+impl Add<Fraction> for i64 {
+    type Output = Fraction;
+
+    fn add(self, other: Fraction) -> Self::Output {
+        Fraction::from_integer(self) + other
+    }
+}
+
+impl Sub<Fraction> for i64 {
+    type Output = Fraction;
+
+    fn sub(self, other: Fraction) -> Self::Output {
+        Fraction::from_integer(self) - other
+    }
+}
+
+impl Mul<Fraction> for i64 {
+    type Output = Fraction;
+
+    fn mul(self, other: Fraction) -> Self::Output {
+        Fraction::from_integer(self) * other
+    }
+}
+
+impl Div<Fraction> for i64 {
+    type Output = Result<Fraction, FractionError>;
+
+    fn div(self, other: Fraction) -> Self::Output {
+        Fraction::from_integer(self) / other
+    }
+}
+
+impl AddAssign for Fraction {
+    fn add_assign(&mut self, other: Fraction) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<i64> for Fraction {
+    fn add_assign(&mut self, other: i64) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Fraction {
+    fn sub_assign(&mut self, other: Fraction) {
+        *self = *self - other;
+    }
+}
+
+impl SubAssign<i64> for Fraction {
+    fn sub_assign(&mut self, other: i64) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for Fraction {
+    fn mul_assign(&mut self, other: Fraction) {
+        *self = *self * other;
+    }
+}
+
+impl MulAssign<i64> for Fraction {
+    fn mul_assign(&mut self, other: i64) {
+        *self = *self * other;
+    }
+}
+
+impl Neg for Fraction {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+/// Sums the fractions, reducing after every addition. An empty iterator sums to `0/1`.
+///
+/// # Panics
+///
+/// Panics if a partial sum overflows `i64` terms; use [`checked_sum`] to get
+/// `FractionError::Overflow` instead.
+impl Sum for Fraction {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::from_integer(0), |total, frac| total + frac)
+    }
+}
+
+impl<'a> Sum<&'a Fraction> for Fraction {
+    fn sum<I: Iterator<Item = &'a Fraction>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Multiplies the fractions, reducing after every step. An empty iterator gives `1/1`.
+///
+/// # Panics
+///
+/// Panics if a partial product overflows `i64` terms; use [`checked_product`]
+/// to get `FractionError::Overflow` instead.
+impl Product for Fraction {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::from_integer(1), |total, frac| total * frac)
+    }
+}
+
+impl<'a> Product<&'a Fraction> for Fraction {
+    fn product<I: Iterator<Item = &'a Fraction>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl From<i64> for Fraction {
+    fn from(n: i64) -> Self {
+        Self::from_integer(n)
+    }
+}
+
+impl From<i32> for Fraction {
+    fn from(n: i32) -> Self {
+        Self::from_integer(i64::from(n))
+    }
+}
+
+impl From<u32> for Fraction {
+    fn from(n: u32) -> Self {
+        Self::from_integer(i64::from(n))
+    }
+}
+
+impl From<u8> for Fraction {
+    fn from(n: u8) -> Self {
+        Self::from_integer(i64::from(n))
+    }
+}
+
+/// Recovers the exact rational value of a float; see [`Fraction::from_f64_exact`].
+impl TryFrom<f64> for Fraction {
+    type Error = FractionError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::from_f64_exact(value)
+    }
+}
+
+impl TryFrom<(i64, i64)> for Fraction {
+    type Error = FractionError;
+
+    fn try_from(value: (i64, i64)) -> Result<Self, Self::Error> {
+        Self::new(value.0, value.1)
+    }
+}
+
+/// Parses `"a/b"`, a whole number `"a"` or a mixed number `"w a/b"`,
+/// ignoring surrounding whitespace.
+///
+/// A leading minus sign (`-` or `−`) is accepted on the numerator or the
+/// denominator, so `"3/-4"` is `-3/4`. In a mixed number the sign goes on the
+/// whole part only and applies to the fractional part too, which must be an
+/// unsigned proper fraction, so `"-1 1/2"` is `-3/2`.
+///
+/// Malformed input, including two integers such as `"1 2"`, is rejected with
+/// `FractionError::ParseError`, a zero denominator with
+/// `FractionError::ZeroDenominator` and out-of-range terms with
+/// `FractionError::Overflow`.
+///
+/// # Examples
+///
+/// ```
+/// use fractions::Fraction;
+///
+/// assert_eq!("-3/4".parse::<Fraction>()?, Fraction::new(-3, 4)?);
+/// assert_eq!(" 5 ".parse::<Fraction>()?, Fraction::from_integer(5));
+/// assert_eq!("1 1/2".parse::<Fraction>()?, Fraction::new(3, 2)?);
+/// # Ok::<(), fractions::FractionError>(())
+/// ```
+impl FromStr for Fraction {
+    type Err = FractionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let Some((whole, fraction)) = s.split_once(char::is_whitespace) else {
+            return parse_ratio(s);
+        };
+
+        let fraction = fraction.trim_start();
+        if !fraction.contains('/')
+            || !fraction.starts_with(|c: char| c.is_ascii_digit())
+            || fraction.contains(['-', '−'])
+        {
+            return Err(FractionError::ParseError);
+        }
+        let fraction = parse_ratio(fraction)?;
+        if fraction.numerator >= fraction.denominator {
+            return Err(FractionError::ParseError);
+        }
+
+        // Checked on the text so that "-0 1/2" is negative too
+        let negative = whole.starts_with(['-', '−']);
+        let whole = Self::from_integer(parse_term(whole)?);
+        if negative {
+            whole.checked_sub(&fraction)
+        } else {
+            whole.checked_add(&fraction)
+        }
+    }
+}
+
+/// Parses `"a/b"` or a whole number `"a"` with no surrounding whitespace.
+fn parse_ratio(s: &str) -> Result<Fraction, FractionError> {
+    let mut parts = s.split('/');
+    let numerator = parse_term(parts.next().unwrap_or_default())?;
+    let denominator = match parts.next() {
+        Some(part) => parse_term(part)?,
+        None => 1,
+    };
+    if parts.next().is_some() {
+        return Err(FractionError::ParseError);
+    }
+    Fraction::new(numerator, denominator)
+}
+
+/// Serializes as the reduced string form produced by `Display`, e.g. `"3/4"`
+/// or `"5"`, which round-trips through [`FromStr`].
+#[cfg(feature = "serde")]
+impl serde::Serialize for Fraction {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes from the string form accepted by [`FromStr`]. Malformed input
+/// and zero denominators are reported as deserialization errors.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Fraction {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FractionVisitor;
+
+        impl serde::de::Visitor<'_> for FractionVisitor {
+            type Value = Fraction;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a fraction string such as \"3/4\"")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Fraction, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(FractionVisitor)
+    }
+}
+
+/// Parses an optionally negative run of ASCII digits.
+fn parse_term(s: &str) -> Result<i64, FractionError> {
+    let (negative, digits) = match s.strip_prefix('-').or_else(|| s.strip_prefix('−')) {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(FractionError::ParseError);
+    }
+
+    let signed = if negative {
+        format!("-{}", digits)
+    } else {
+        digits.to_string()
+    };
+    signed
+        .parse()
+        .map_err(|err: ParseIntError| match err.kind() {
+            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => FractionError::Overflow,
+            _ => FractionError::ParseError,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frac(n: i64, d: i64) -> Fraction {
+        Fraction::new(n, d).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Fraction::new(1, 2).is_ok());
+        assert_eq!(Fraction::new(1, 0), Err(FractionError::ZeroDenominator));
+    }
+
+    #[test]
+    fn test_negative_denominator() {
+        let frac = Fraction::new(1, -2).unwrap();
+        assert_eq!(frac.numerator, -1);
+        assert_eq!(frac.denominator, 2);
+    }
+
+    #[test]
+    fn test_reduce() {
+        let frac = Fraction::new_unreduced(12, 8).unwrap();
+        let reduced = frac.reduce();
+        assert_eq!(reduced.numerator, 3);
+        assert_eq!(reduced.denominator, 2);
+    }
+
+    #[test]
+    fn test_sign_normalization_overflow() {
+        assert_eq!(Fraction::new(i64::MIN, -1), Err(FractionError::Overflow));
+        assert_eq!(
+            Fraction::new_unreduced(i64::MIN, -1),
+            Err(FractionError::Overflow)
+        );
+        assert_eq!(
+            Fraction::new_unreduced(1, i64::MIN),
+            Err(FractionError::Overflow)
+        );
+        let raw = Fraction::new_unreduced(i64::MIN, 2).unwrap();
+        assert_eq!((raw.numerator, raw.denominator), (i64::MIN, 2));
+        let raw = Fraction::new_unreduced(i64::MAX, -2).unwrap();
+        assert_eq!((raw.numerator, raw.denominator), (-i64::MAX, 2));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Fraction::new(3, 4).unwrap().to_string(), "3/4");
+        assert_eq!(Fraction::new(4, 2).unwrap().to_string(), "2");
+        assert_eq!(Fraction::new(-3, 4).unwrap().to_string(), "-3/4");
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let half = Fraction::new(1, 2).unwrap();
+        let third = Fraction::new(1, 3).unwrap();
+
+        let sum = half.add(third).reduce();
+        assert_eq!(sum.numerator, 5);
+        assert_eq!(sum.denominator, 6);
+
+        let diff = half.subtract(&third).reduce();
+        assert_eq!(diff.numerator, 1);
+        assert_eq!(diff.denominator, 6);
+
+        let product = half.multiply(&third).reduce();
+        assert_eq!(product.numerator, 1);
+        assert_eq!(product.denominator, 6);
+
+        let quotient = half.divide(&third).unwrap().reduce();
+        assert_eq!(quotient.numerator, 3);
+        assert_eq!(quotient.denominator, 2);
+    }
+
+    #[test]
+    fn test_operators() {
+        let half = Fraction::new(1, 2).unwrap();
+        let third = Fraction::new(1, 3).unwrap();
+
+        let sum = (half + third).reduce();
+        assert_eq!(sum.numerator, 5);
+        assert_eq!(sum.denominator, 6);
+
+        let diff = (half - third).reduce();
+        assert_eq!(diff.numerator, 1);
+        assert_eq!(diff.denominator, 6);
+
+        let product = (half * third).reduce();
+        assert_eq!(product.numerator, 1);
+        assert_eq!(product.denominator, 6);
+
+        let quotient = (half / third).unwrap().reduce();
+        assert_eq!(quotient.numerator, 3);
+        assert_eq!(quotient.denominator, 2);
+
+        let neg = -half;
+        assert_eq!(neg.numerator, -1);
+        assert_eq!(neg.denominator, 2);
+    }
+
+    #[test]
+    fn test_comparison() {
+        let half = Fraction::new(1, 2).unwrap();
+        let third = Fraction::new(1, 3).unwrap();
+        let also_half = Fraction::new(2, 4).unwrap();
+
+        assert!(half > third);
+        assert!(third < half);
+        assert_eq!(half, also_half);
+        assert_ne!(half, third);
+    }
+
+    #[test]
+    fn test_utility_methods() {
+        let half = Fraction::new(1, 2).unwrap();
+        let neg_half = Fraction::new(-1, 2).unwrap();
+        let zero = Fraction::from_integer(0);
+        let five = Fraction::from_integer(5);
+
+        assert_eq!(half.numerator(), 1);
+        assert_eq!(half.denominator(), 2);
+        assert_eq!(half.to_f64(), 0.5);
+
+        assert!(half.is_positive());
+        assert!(!half.is_negative());
+        assert!(!half.is_zero());
+
+        assert!(neg_half.is_negative());
+        assert!(!neg_half.is_positive());
+
+        assert!(zero.is_zero());
+        assert!(!zero.is_positive());
+        assert!(!zero.is_negative());
+
+        assert_eq!(neg_half.abs(), half);
+        assert_eq!(five.to_string(), "5");
+    }
+
+    #[test]
+    fn test_conversions() {
+        let five: Fraction = 5.into();
+        assert_eq!(five, Fraction::new(5, 1).unwrap());
+
+        let frac: Fraction = (3, 4).try_into().unwrap();
+        assert_eq!(frac, Fraction::new(3, 4).unwrap());
+
+        let zero_den: Result<Fraction, _> = (1, 0).try_into();
+        assert!(zero_den.is_err());
+    }
+
+    #[test]
+    fn test_integer_divisors() {
+        let twelve = Fraction::new(12, 1).unwrap();
+        assert_eq!(twelve.integer_divisors().unwrap(), vec![1, 2, 3, 4, 6, 12]);
+
+        let neg = Fraction::new(-18, 2).unwrap();
+        assert_eq!(neg.integer_divisors().unwrap(), vec![1, 3, 9]);
+
+        let half = Fraction::new(1, 2).unwrap();
+        assert_eq!(half.integer_divisors(), Err(FractionError::NotAnInteger));
+
+        assert!(
+            Fraction::from_integer(0)
+                .integer_divisors()
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_solve_2x2() {
+        // x/2 + y = 2, 3x - y/3 = 1
+        let a = [[frac(1, 2), frac(1, 1)], [frac(3, 1), frac(-1, 3)]];
+        let b = [frac(2, 1), frac(1, 1)];
+        let [x, y] = solve_2x2(a, b).unwrap();
+        assert_eq!(x, frac(10, 19));
+        assert_eq!(y, frac(33, 19));
+        assert_eq!(a[0][0] * x + a[0][1] * y, b[0]);
+        assert_eq!(a[1][0] * x + a[1][1] * y, b[1]);
+
+        let singular = [[frac(1, 2), frac(1, 1)], [frac(1, 1), frac(2, 1)]];
+        assert_eq!(solve_2x2(singular, b), Err(FractionError::DivisionByZero));
+
+        let large = [[frac(i64::MAX, 1), frac(1, 1)], [frac(1, 1), frac(1, 1)]];
+        assert_eq!(
+            solve_2x2(large, [frac(1, 1), frac(1, 1)]),
+            Ok([frac(0, 1), frac(1, 1)])
+        );
+        let wide = [
+            [frac(i64::MAX, 1), frac(1, 1)],
+            [frac(-1, 1), frac(i64::MAX, 1)],
+        ];
+        assert_eq!(solve_2x2(wide, b), Err(FractionError::Overflow));
+    }
+
+    #[test]
+    fn test_from_f64_exact() {
+        assert_eq!(
+            Fraction::from_f64_exact(0.5).unwrap(),
+            Fraction::new(1, 2).unwrap()
+        );
+        assert_eq!(
+            Fraction::from_f64_exact(-2.75).unwrap(),
+            Fraction::new(-11, 4).unwrap()
+        );
+        assert_eq!(
+            Fraction::from_f64_exact(6.0).unwrap(),
+            Fraction::from_integer(6)
+        );
+        assert!(Fraction::from_f64_exact(0.0).unwrap().is_zero());
+        assert_eq!(
+            Fraction::from_f64_exact(f64::NAN),
+            Err(FractionError::NotFinite)
+        );
+        assert_eq!(
+            Fraction::from_f64_exact(1e300),
+            Err(FractionError::Overflow)
+        );
+        assert_eq!(
+            Fraction::from_f64_exact(1e-300),
+            Err(FractionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_f64_conversion_error() {
+        let dyadic = Fraction::new(-5, 16).unwrap();
+        assert!(dyadic.f64_conversion_error().unwrap().is_zero());
+
+        let third = Fraction::new(1, 3).unwrap();
+        let error = third.f64_conversion_error().unwrap();
+        assert!(!error.is_zero());
+        assert!(error.abs() < Fraction::new(1, 1 << 53).unwrap());
+    }
+
+    #[test]
+    fn test_reduce_all() {
+        let mut values = [
+            Fraction::new_unreduced(12, 8).unwrap(),
+            Fraction::new_unreduced(-10, 15).unwrap(),
+            Fraction::new_unreduced(0, 7).unwrap(),
+            Fraction::new_unreduced(5, 3).unwrap(),
+        ];
+        reduce_all(&mut values);
+
+        let terms: Vec<_> = values
+            .iter()
+            .map(|f| (f.numerator(), f.denominator()))
+            .collect();
+        assert_eq!(terms, vec![(3, 2), (-2, 3), (0, 1), (5, 3)]);
+    }
+
+    #[test]
+    fn test_common_base() {
+        let rates = [
+            Fraction::new(1, 2).unwrap(),
+            Fraction::new(1, 3).unwrap(),
+            Fraction::new(1, 4).unwrap(),
+        ];
+        assert_eq!(common_base(&rates), Ok(12));
+        assert_eq!(common_base(&[Fraction::new(4, 8).unwrap()]), Ok(2));
+        assert_eq!(common_base(&[]), Ok(1));
+
+        let huge = [
+            Fraction::new(1, i64::MAX).unwrap(),
+            Fraction::new(1, i64::MAX - 1).unwrap(),
+        ];
+        assert_eq!(common_base(&huge), Err(FractionError::Overflow));
+    }
+
+    #[test]
+    fn test_with_decimal() {
+        assert_eq!(WithDecimal(frac(3, 4), 4).to_string(), "3/4 (0.7500)");
+        assert_eq!(WithDecimal(frac(2, 3), 3).to_string(), "2/3 (0.667)");
+        assert_eq!(WithDecimal(frac(-1, 8), 2).to_string(), "-1/8 (-0.13)");
+        assert_eq!(WithDecimal(frac(-1, 300), 2).to_string(), "-1/300 (0.00)");
+        assert_eq!(
+            WithDecimal(frac(1999, 2000), 2).to_string(),
+            "1999/2000 (1.00)"
+        );
+        assert_eq!(WithDecimal(frac(5, 2), 0).to_string(), "5/2 (3)");
+    }
+
+    #[test]
+    fn test_clamp_magnitude() {
+        let limit = Fraction::new(3, 2).unwrap();
+
+        let fast = Fraction::new(5, 2).unwrap();
+        assert_eq!(fast.clamp_magnitude(&limit), limit);
+
+        let fast_back = Fraction::new(-9, 4).unwrap();
+        assert_eq!(fast_back.clamp_magnitude(&limit), -limit);
+
+        let slow = Fraction::new(-1, 2).unwrap();
+        assert_eq!(slow.clamp_magnitude(&limit), slow);
+    }
+
+    #[test]
+    fn test_scale_to_integer() {
+        let frac = Fraction::new(3, 4).unwrap();
+        assert_eq!(frac.integerizing_factor(), 4);
+        assert_eq!(frac.scale_to_integer(), 3);
+
+        let neg = Fraction::new(-10, 4).unwrap();
+        assert_eq!(neg.integerizing_factor(), 2);
+        assert_eq!(neg.scale_to_integer(), -5);
+
+        let whole = Fraction::new(14, 2).unwrap();
+        assert_eq!(whole.integerizing_factor(), 1);
+        assert_eq!(whole.scale_to_integer(), 7);
+    }
+
+    #[test]
+    fn test_farey_parents() {
+        let (left, right) = frac(2, 5).farey_parents().unwrap();
+        assert_eq!((left, right), (frac(1, 3), frac(1, 2)));
+
+        let (left, right) = frac(1, 2).farey_parents().unwrap();
+        assert_eq!((left, right), (frac(0, 1), frac(1, 1)));
+
+        let (left, right) = frac(6, 10).farey_parents().unwrap();
+        assert_eq!((left, right), (frac(1, 2), frac(2, 3)));
+
+        let (left, right) = frac(3, 2).farey_parents().unwrap();
+        assert_eq!((left, right), (frac(1, 1), frac(2, 1)));
+
+        let (left, right) = frac(7, 3).farey_parents().unwrap();
+        assert_eq!((left, right), (frac(2, 1), frac(5, 2)));
+
+        let (left, right) = frac(i64::MAX, i64::MAX - 1).farey_parents().unwrap();
+        assert_eq!(
+            (left, right),
+            (frac(1, 1), frac(i64::MAX - 1, i64::MAX - 2))
+        );
+
+        assert_eq!(frac(0, 1).farey_parents(), None);
+        assert_eq!(frac(-1, 2).farey_parents(), None);
+        assert_eq!(frac(1, 1).farey_parents(), None);
+        assert_eq!(frac(3, 1).farey_parents(), None);
+    }
+
+    #[test]
+    fn test_scientific_string() {
+        assert_eq!(frac(12345, 10).to_scientific_string(4), "1.2345e3");
+        assert_eq!(frac(12345, 10).to_scientific_string(2), "1.23e3");
+        assert_eq!(frac(i64::MAX, 1).to_scientific_string(3), "9.223e18");
+        assert_eq!(frac(1, 3000).to_scientific_string(3), "3.333e-4");
+        assert_eq!(frac(-2, 3).to_scientific_string(1), "-6.7e-1");
+        assert_eq!(frac(9999, 1000).to_scientific_string(2), "1.00e1");
+        assert_eq!(frac(5, 1).to_scientific_string(0), "5e0");
+        assert_eq!(frac(0, 7).to_scientific_string(3), "0e0");
+    }
+
+    #[test]
+    fn test_lerp_clamped() {
+        let start = Fraction::new(1, 3).unwrap();
+        let end = Fraction::new(5, 6).unwrap();
+
+        let past_end = Fraction::new(3, 2).unwrap();
+        assert_eq!(start.lerp_clamped(&end, &past_end), Ok(end));
+
+        let before_start = Fraction::new(-1, 2).unwrap();
+        assert_eq!(start.lerp_clamped(&end, &before_start), Ok(start));
+
+        let half = Fraction::new(1, 2).unwrap();
+        assert_eq!(
+            start.lerp_clamped(&end, &half),
+            Ok(Fraction::new(7, 12).unwrap())
+        );
+
+        // The difference of the endpoints overflows, the interpolation does not
+        let low = Fraction::from(-i64::MAX);
+        let high = Fraction::from(i64::MAX);
+        assert_eq!(low.lerp_clamped(&high, &half), Ok(Fraction::ZERO));
+        assert_eq!(low.lerp_clamped(&high, &past_end), Ok(high));
+        assert_eq!(
+            Fraction::new(1, i64::MAX)
+                .unwrap()
+                .lerp_clamped(&Fraction::new(1, i64::MAX - 1).unwrap(), &half),
+            Err(FractionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_safe_cmp() {
+        let terms = [
+            i64::MIN + 1,
+            -1_000_000_007,
+            -3,
+            -1,
+            0,
+            1,
+            2,
+            7,
+            1 << 40,
+            i64::MAX,
+        ];
+        let dens = [1, 2, 3, 1_000_000_007, 1 << 40, i64::MAX];
+
+        let mut values = Vec::new();
+        for &n in &terms {
+            for &d in &dens {
+                values.push(Fraction::new(n, d).unwrap());
+            }
+        }
+
+        // Where the i64 cross products fit they give an independent reference
+        for a in &values {
+            for b in &values {
+                let lhs = a.numerator.checked_mul(b.denominator);
+                let rhs = b.numerator.checked_mul(a.denominator);
+                if let (Some(lhs), Some(rhs)) = (lhs, rhs) {
+                    assert_eq!(a.safe_cmp(b), lhs.cmp(&rhs), "{a} vs {b}");
+                }
+            }
+        }
+
+        let big_half = Fraction::new(i64::MAX, 2).unwrap();
+        let big_third = Fraction::new(i64::MAX, 3).unwrap();
+        assert_eq!(big_half.safe_cmp(&big_third), Ordering::Greater);
+        assert_eq!((-big_half).safe_cmp(&-big_third), Ordering::Less);
+        assert_eq!(big_half.safe_cmp(&big_half), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_add_f64_exact() {
+        let third = Fraction::new(1, 3).unwrap();
+        assert_eq!(third.add_f64_exact(0.5), Ok(Fraction::new(5, 6).unwrap()));
+        assert_eq!(
+            third.add_f64_exact(-0.25),
+            Ok(Fraction::new(1, 12).unwrap())
+        );
+        assert_eq!(third.add_f64_exact(f64::NAN), Err(FractionError::NotFinite));
+        assert_eq!(
+            third.add_f64_exact(f64::NEG_INFINITY),
+            Err(FractionError::NotFinite)
+        );
+        assert_eq!(third.add_f64_exact(1e30), Err(FractionError::Overflow));
+    }
+
+    #[test]
+    fn test_geometric_sequence() {
+        let doubling = Fraction::geometric_sequence(frac(3, 6), frac(2, 1), 4).unwrap();
+        assert_eq!(
+            doubling,
+            vec![frac(1, 2), frac(1, 1), frac(2, 1), frac(4, 1)]
+        );
+        assert_eq!(doubling[0].denominator(), 2);
+
+        let halving = Fraction::geometric_sequence(frac(1, 1), frac(1, 2), 4).unwrap();
+        assert_eq!(
+            halving,
+            vec![frac(1, 1), frac(1, 2), frac(1, 4), frac(1, 8)]
+        );
+
+        assert!(
+            Fraction::geometric_sequence(frac(1, 1), frac(2, 1), 0)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            Fraction::geometric_sequence(frac(1, 1), frac(2, 1), 70),
+            Err(FractionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_dedup_fractions() {
+        let mut values = vec![frac(1, 2), frac(2, 4), frac(1, 3), frac(2, 4)];
+        dedup_fractions(&mut values);
+        assert_eq!(values, vec![frac(1, 2), frac(1, 3)]);
+
+        let raw = |n, d| Fraction::new_unreduced(n, d).unwrap();
+        let mut mixed = vec![raw(-3, 9), raw(0, 5), raw(1, -3), raw(0, 1), raw(2, 6)];
+        dedup_fractions(&mut mixed);
+        let terms: Vec<_> = mixed
+            .iter()
+            .map(|f| (f.numerator(), f.denominator()))
+            .collect();
+        assert_eq!(terms, vec![(-3, 9), (0, 5), (2, 6)]);
+    }
+
+    #[test]
+    fn test_powers_of() {
+        let doubling: Vec<_> = powers_of(frac(2, 1)).take(5).collect();
+        assert_eq!(
+            doubling,
+            vec![frac(1, 1), frac(2, 1), frac(4, 1), frac(8, 1), frac(16, 1)]
+        );
+
+        let halving: Vec<_> = powers_of(frac(2, 4)).take(4).collect();
+        assert_eq!(
+            halving,
+            vec![frac(1, 1), frac(1, 2), frac(1, 4), frac(1, 8)]
+        );
+        assert_eq!(halving[3].denominator(), 8);
+
+        // 2^0 through 2^62 fit in an i64, 2^63 does not
+        assert_eq!(powers_of(frac(2, 1)).count(), 63);
+    }
+
+    #[test]
+    fn test_mobius() {
+        let x = frac(3, 5);
+
+        assert_eq!(x.mobius(1, 0, 0, 1), Ok(x));
+        assert_eq!(x.mobius(0, 1, 1, 0), Ok(frac(5, 3)));
+        // (2*3/5 - 1) / (3/5 + 4) = (1/5) / (23/5)
+        assert_eq!(x.mobius(2, -1, 1, 4), Ok(frac(1, 23)));
+        assert_eq!(x.mobius(1, 1, 5, -3), Err(FractionError::DivisionByZero));
+    }
+
+    #[test]
+    fn test_round_to_step_half_even() {
+        let quarter = frac(1, 4);
+
+        // Halfway between 1/4 (odd multiple) and 1/2 (even multiple)
+        assert_eq!(frac(3, 8).round_to_step_half_even(&quarter), Ok(frac(1, 2)));
+        // Halfway between 1/2 (even multiple) and 3/4 (odd multiple)
+        assert_eq!(frac(5, 8).round_to_step_half_even(&quarter), Ok(frac(1, 2)));
+        assert_eq!(
+            frac(-3, 8).round_to_step_half_even(&quarter),
+            Ok(frac(-1, 2))
+        );
+        assert_eq!(frac(2, 3).round_to_step_half_even(&quarter), Ok(frac(3, 4)));
+        assert_eq!(
+            frac(7, 10).round_to_step_half_even(&frac(-1, 5)),
+            Ok(frac(4, 5))
+        );
+        assert_eq!(
+            frac(1, 2).round_to_step_half_even(&frac(0, 1)),
+            Err(FractionError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_to_string_with_integer_suffix() {
+        assert_eq!(
+            frac(5, 1).to_string_with_integer_suffix(" units"),
+            "5 units"
+        );
+        assert_eq!(frac(-12, 4).to_string_with_integer_suffix("px"), "-3px");
+        assert_eq!(frac(3, 4).to_string_with_integer_suffix(" units"), "3/4");
+        assert_eq!(frac(6, -8).to_string_with_integer_suffix(" units"), "-3/4");
+    }
+
+    #[test]
+    fn test_running_extremes() {
+        let values = [frac(1, 3), frac(1, 2), frac(1, 4), frac(-1, 1), frac(3, 4)];
+
+        assert_eq!(
+            running_max(&values),
+            vec![frac(1, 3), frac(1, 2), frac(1, 2), frac(1, 2), frac(3, 4)]
+        );
+        assert_eq!(
+            running_min(&values),
+            vec![frac(1, 3), frac(1, 3), frac(1, 4), frac(-1, 1), frac(-1, 1)]
+        );
+        assert_eq!(
+            running_max(&values[..3]),
+            vec![frac(1, 3), frac(1, 2), frac(1, 2)]
+        );
+        assert!(running_max(&[]).is_empty());
+        assert!(running_min(&[]).is_empty());
+
+        let big = [frac(i64::MAX, 3), frac(i64::MAX, 2)];
+        assert_eq!(running_max(&big)[1].denominator(), 2);
+    }
+
+    #[test]
+    fn test_floor_ceil_div() {
+        let one = frac(1, 1);
+
+        assert_eq!(frac(7, 2).floor_div(&one), Ok(3));
+        assert_eq!(frac(7, 2).ceil_div(&one), Ok(4));
+        assert_eq!(frac(-7, 2).floor_div(&one), Ok(-4));
+        assert_eq!(frac(-7, 2).ceil_div(&one), Ok(-3));
+        assert_eq!(frac(7, 2).floor_div(&frac(-1, 2)), Ok(-7));
+        assert_eq!(frac(5, 6).floor_div(&frac(-1, 4)), Ok(-4));
+        assert_eq!(frac(5, 6).ceil_div(&frac(-1, 4)), Ok(-3));
+        assert_eq!(
+            frac(1, 2).floor_div(&frac(0, 1)),
+            Err(FractionError::DivisionByZero)
+        );
+        assert_eq!(
+            frac(i64::MAX, 1).ceil_div(&frac(1, 2)),
+            Err(FractionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_percentage_change() {
+        assert_eq!(frac(3, 2).percentage_change(&frac(1, 1)), Ok(frac(50, 1)));
+        assert_eq!(frac(1, 2).percentage_change(&frac(2, 1)), Ok(frac(-75, 1)));
+        assert_eq!(frac(1, 3).percentage_change(&frac(1, 4)), Ok(frac(100, 3)));
+        assert_eq!(
+            frac(-1, 2).percentage_change(&frac(-1, 4)),
+            Ok(frac(100, 1))
+        );
+        assert_eq!(
+            frac(1, 2).percentage_change(&frac(0, 1)),
+            Err(FractionError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_compare_detailed() {
+        assert_eq!(
+            frac(1, 2).compare_detailed(&frac(1, 3)),
+            Ok((Ordering::Greater, frac(1, 6)))
+        );
+        assert_eq!(
+            frac(-1, 4).compare_detailed(&frac(1, 4)),
+            Ok((Ordering::Less, frac(1, 2)))
+        );
+
+        let (order, diff) = frac(2, 4).compare_detailed(&frac(1, 2)).unwrap();
+        assert_eq!(order, Ordering::Equal);
+        assert!(diff.is_zero());
+        assert_eq!(diff.denominator(), 1);
+
+        assert_eq!(
+            frac(i64::MAX, 2).compare_detailed(&frac(-i64::MAX, 3)),
+            Err(FractionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_from_float_ratio() {
+        assert_eq!(Fraction::from_float_ratio(1.0, 3.0, 100), Ok(frac(1, 3)));
+        assert_eq!(Fraction::from_float_ratio(-2.0, 6.0, 100), Ok(frac(-1, 3)));
+        assert_eq!(Fraction::from_float_ratio(22.0, 7.0, 10), Ok(frac(22, 7)));
+        assert_eq!(
+            Fraction::from_float_ratio(std::f64::consts::PI, 1.0, 1000),
+            Ok(frac(355, 113))
+        );
+        assert_eq!(Fraction::from_float_ratio(0.7, 1.0, 2), Ok(frac(1, 2)));
+        assert_eq!(Fraction::from_float_ratio(5.0, 3.0, 1), Ok(frac(2, 1)));
+        assert_eq!(Fraction::from_float_ratio(1e-30, 1.0, 1000), Ok(frac(0, 1)));
+
+        assert_eq!(
+            Fraction::from_float_ratio(1.0, 0.0, 100),
+            Err(FractionError::ZeroDenominator)
+        );
+        assert_eq!(
+            Fraction::from_float_ratio(1.0, f64::NAN, 100),
+            Err(FractionError::NotFinite)
+        );
+        assert_eq!(
+            Fraction::from_float_ratio(1.0, 3.0, 0),
+            Err(FractionError::InvalidArgument)
+        );
+        assert_eq!(
+            Fraction::from_float_ratio(1e300, 1.0, 100),
+            Err(FractionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_split_integers() {
+        let parts = split_integers(&[frac(7, 2), frac(5, 1), frac(1, 3)]);
+        assert_eq!(
+            parts,
+            vec![(3, frac(1, 2)), (5, frac(0, 1)), (0, frac(1, 3))]
+        );
+
+        let (whole, rest) = frac(-14, 4).split_integer();
+        assert_eq!(whole, -3);
+        assert_eq!((rest.numerator(), rest.denominator()), (-1, 2));
+    }
+
+    #[test]
+    fn test_from_continued_fraction() {
+        assert_eq!(
+            Fraction::from_continued_fraction(&[3, 7, 15, 1]),
+            Ok(frac(355, 113))
+        );
+        assert_eq!(
+            Fraction::from_continued_fraction(&[0, 2, 3]),
+            Ok(frac(3, 7))
+        );
+        assert_eq!(
+            Fraction::from_continued_fraction(&[-3, 1, 2]),
+            Ok(frac(-7, 3))
+        );
+        assert_eq!(Fraction::from_continued_fraction(&[5]), Ok(frac(5, 1)));
+
+        assert_eq!(
+            Fraction::from_continued_fraction(&[1, i64::MAX, 2]),
+            Err(FractionError::Overflow)
+        );
+        let deep = [1_000_000; 4];
+        assert_eq!(
+            Fraction::from_continued_fraction(&deep),
+            Err(FractionError::Overflow)
+        );
+        assert_eq!(
+            Fraction::from_continued_fraction(&[]),
+            Err(FractionError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn test_accounting_string() {
+        assert_eq!(frac(-3, 4).to_accounting_string(), "(3/4)");
+        assert_eq!(frac(10, -2).to_accounting_string(), "(5)");
+        assert_eq!(frac(9, 12).to_accounting_string(), "3/4");
+        assert_eq!(frac(0, 3).to_accounting_string(), "0");
+    }
+
+    #[test]
+    fn test_reduced_iterator() {
+        let values = [
+            Fraction::new(12, 8).unwrap(),
+            Fraction::new(3, 9).unwrap(),
+            Fraction::new(5, 7).unwrap(),
+        ];
+
+        let mut reduced = values.iter().copied().reduced();
+        assert_eq!(reduced.size_hint(), (3, Some(3)));
+
+        let terms: Vec<_> = reduced
+            .by_ref()
+            .map(|f| (f.numerator(), f.denominator()))
+            .collect();
+        assert_eq!(terms, vec![(3, 2), (1, 3), (5, 7)]);
+        assert!(reduced.next().is_none());
+    }
+
+    #[test]
+    fn test_weighted_median() {
+        let items = [
+            (frac(3, 4), 2),
+            (frac(1, 4), 1),
+            (frac(1, 2), 4),
+            (frac(1, 1), 1),
+        ];
+        assert_eq!(weighted_median(&items), Some(frac(1, 2)));
+
+        // Cumulative weight hits exactly half after 1/4, so average with 1/2
+        let even = [(frac(1, 2), 2), (frac(1, 4), 2), (frac(5, 1), 0)];
+        assert_eq!(weighted_median(&even), Some(frac(3, 8)));
+
+        assert_eq!(weighted_median(&[]), None);
+        assert_eq!(weighted_median(&[(frac(1, 2), 0)]), None);
+        assert_eq!(weighted_median(&[(frac(1, 2), -1), (frac(1, 3), 2)]), None);
+
+        let wide = [(frac(1, i64::MAX), 1), (frac(1, i64::MAX - 1), 1)];
+        assert_eq!(weighted_median(&wide), None);
+        let large = [(frac(i64::MAX, 1), 1), (frac(i64::MAX - 2, 1), 1)];
+        assert_eq!(weighted_median(&large), Some(frac(i64::MAX - 1, 1)));
+    }
+
+    #[test]
+    fn test_arithmetic_progression() {
+        assert!(is_arithmetic_progression(
+            &frac(1, 4),
+            &frac(1, 2),
+            &frac(3, 4)
+        ));
+        assert!(!is_arithmetic_progression(
+            &frac(1, 4),
+            &frac(1, 2),
+            &frac(1, 1)
+        ));
+
+        assert!(is_arithmetic_sequence(&[
+            frac(1, 4),
+            frac(2, 4),
+            frac(3, 4),
+            frac(1, 1)
+        ]));
+        assert!(is_arithmetic_sequence(&[
+            frac(1, 3),
+            frac(0, 1),
+            frac(-1, 3)
+        ]));
+        assert!(!is_arithmetic_sequence(&[
+            frac(1, 4),
+            frac(1, 2),
+            frac(3, 4),
+            frac(3, 2)
+        ]));
+        assert!(is_arithmetic_sequence(&[frac(1, 2), frac(7, 3)]));
+        assert!(is_arithmetic_sequence(&[]));
+
+        // Differences that overflow i64 terms are still compared exactly
+        let (lo, hi) = (frac(1, i64::MAX), frac(1, i64::MAX - 1));
+        assert!(!is_arithmetic_progression(&lo, &hi, &lo));
+        assert!(is_arithmetic_progression(
+            &frac(-i64::MAX, 1),
+            &frac(0, 1),
+            &frac(i64::MAX, 1)
+        ));
+        assert!(!is_arithmetic_sequence(&[
+            frac(i64::MIN, 1),
+            frac(i64::MAX, 1),
+            frac(i64::MIN, 1)
+        ]));
+    }
+
+    #[test]
+    fn test_geometric_sequence_detection() {
+        assert!(is_geometric_sequence(&[frac(1, 1), frac(1, 2), frac(1, 4)]));
+        assert!(is_geometric_sequence(&[
+            frac(2, 3),
+            frac(-4, 3),
+            frac(8, 3),
+            frac(-16, 3)
+        ]));
+        assert!(!is_geometric_sequence(&[
+            frac(1, 1),
+            frac(1, 2),
+            frac(1, 3)
+        ]));
+        assert!(!is_geometric_sequence(&[
+            frac(1, 1),
+            frac(0, 1),
+            frac(0, 1)
+        ]));
+        assert!(!is_geometric_sequence(&[
+            frac(0, 1),
+            frac(0, 1),
+            frac(0, 1)
+        ]));
+        assert!(!is_geometric_sequence(&[
+            frac(0, 1),
+            frac(1, 1),
+            frac(2, 1)
+        ]));
+        assert!(is_geometric_sequence(&[frac(3, 1)]));
+        assert!(is_geometric_sequence(&[frac(0, 1), frac(5, 1)]));
+
+        // b * b = 2^80 would overflow i64 terms
+        let big = frac(1 << 40, 1);
+        assert!(is_geometric_sequence(&[
+            frac(1 << 20, 1),
+            big,
+            frac(1 << 60, 1)
+        ]));
+        assert!(!is_geometric_sequence(&[
+            frac(1 << 20, 1),
+            big,
+            frac(1 << 61, 1)
+        ]));
+    }
+
+    #[test]
+    fn test_to_mixed_radix() {
+        let feet_inches = [12, 16];
+
+        assert_eq!(
+            frac(435, 192).to_mixed_radix(&feet_inches),
+            Ok(vec![2, 3, 3])
+        );
+        assert_eq!(frac(31, 12).to_mixed_radix(&feet_inches), Ok(vec![2, 7, 0]));
+        assert_eq!(
+            frac(-387, 192).to_mixed_radix(&feet_inches),
+            Ok(vec![-2, 0, -3])
+        );
+        // One third of an inch truncates to five sixteenths
+        assert_eq!(frac(1, 36).to_mixed_radix(&feet_inches), Ok(vec![0, 0, 5]));
+        assert_eq!(frac(7, 2).to_mixed_radix(&[]), Ok(vec![3]));
+        assert_eq!(
+            frac(1, 2).to_mixed_radix(&[12, 0]),
+            Err(FractionError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn test_round_to_denominator() {
+        let value = frac(3, 8);
+
+        assert_eq!(value.round_to_denominator(16), Ok(frac(3, 8)));
+        assert_eq!(value.round_to_denominator(4), Ok(frac(1, 2)));
+        assert_eq!((-value).round_to_denominator(4), Ok(frac(-1, 2)));
+        assert_eq!(frac(1, 3).round_to_denominator(8), Ok(frac(3, 8)));
+        assert_eq!(frac(1, 3).round_to_denominator(1), Ok(frac(0, 1)));
+        assert_eq!(
+            value.round_to_denominator(0),
+            Err(FractionError::ZeroDenominator)
+        );
+    }
+
+    #[test]
+    fn test_f64_total_order_key() {
+        let third = Fraction::new(1, 3).unwrap();
+        let near_third = Fraction::new(333_333_333_333_333_333, 1_000_000_000_000_000_000).unwrap();
+        assert_eq!(third.to_f64(), near_third.to_f64());
+
+        let (third_bits, _) = third.f64_total_order_key();
+        let (near_bits, _) = near_third.f64_total_order_key();
+        assert_eq!(third_bits, near_bits);
+        assert!(near_third.f64_total_order_key() < third.f64_total_order_key());
+
+        let mut forward = [third, near_third];
+        let mut backward = [near_third, third];
+        forward.sort_by_key(Fraction::f64_total_order_key);
+        backward.sort_by_key(Fraction::f64_total_order_key);
+        assert_eq!(forward[0].denominator(), backward[0].denominator());
+        assert_eq!(forward[0].denominator(), 1_000_000_000_000_000_000);
+
+        let values = [-3.5, -0.25, 0.0, 0.125, 2.0];
+        for pair in values.windows(2) {
+            let a = Fraction::from_f64_exact(pair[0]).unwrap();
+            let b = Fraction::from_f64_exact(pair[1]).unwrap();
+            assert_eq!(
+                a.f64_total_order_key().cmp(&b.f64_total_order_key()),
+                pair[0].total_cmp(&pair[1])
+            );
+        }
+    }
+
+    #[test]
+    fn test_expected_value() {
+        let outcomes = [(frac(1, 3), frac(3, 1)), (frac(2, 3), frac(-3, 4))];
+        assert_eq!(expected_value(&outcomes), Ok(frac(1, 2)));
+        assert_eq!(validate_distribution(&outcomes), Ok(()));
+
+        assert_eq!(expected_value(&[]), Ok(frac(0, 1)));
+
+        let short = [(frac(1, 3), frac(1, 1)), (frac(1, 3), frac(2, 1))];
+        assert_eq!(
+            validate_distribution(&short),
+            Err(FractionError::InvalidArgument)
+        );
+        let negative = [(frac(3, 2), frac(1, 1)), (frac(-1, 2), frac(2, 1))];
+        assert_eq!(
+            validate_distribution(&negative),
+            Err(FractionError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn test_with_sign_on_denominator() {
+        assert_eq!(frac(-3, 4).with_sign_on_denominator(), Ok((3, -4)));
+        assert_eq!(frac(3, 4).with_sign_on_denominator(), Ok((3, 4)));
+        let raw = Fraction::new_unreduced(6, -8).unwrap();
+        assert_eq!(raw.with_sign_on_denominator(), Ok((6, -8)));
+        assert_eq!(frac(6, -8).with_sign_on_denominator(), Ok((3, -4)));
+        assert_eq!(frac(0, 5).with_sign_on_denominator(), Ok((0, 1)));
+        assert_eq!(
+            Fraction::from(i64::MIN).with_sign_on_denominator(),
+            Err(FractionError::Overflow)
+        );
+        assert_eq!(
+            frac(i64::MIN + 1, 2).with_sign_on_denominator(),
+            Ok((i64::MAX, -2))
+        );
+    }
+
+    #[test]
+    fn test_try_from_f32_exact() {
+        assert_eq!(Fraction::try_from_f32_exact(0.5), Ok(frac(1, 2)));
+        assert_eq!(Fraction::try_from_f32_exact(-1.375), Ok(frac(-11, 8)));
+        assert_eq!(Fraction::try_from_f32_exact(1024.0), Ok(frac(1024, 1)));
+        assert_eq!(
+            Fraction::try_from_f32_exact(0.1),
+            Ok(frac(13421773, 134217728))
+        );
+        assert_eq!(
+            Fraction::try_from_f32_exact(1e30),
+            Err(FractionError::Overflow)
+        );
+        assert_eq!(
+            Fraction::try_from_f32_exact(1e-30),
+            Err(FractionError::Overflow)
+        );
+        assert_eq!(
+            Fraction::try_from_f32_exact(f32::INFINITY),
+            Err(FractionError::NotFinite)
+        );
+    }
+
+    #[test]
+    fn test_numerators_gcd() {
+        assert_eq!(numerators_gcd(&[frac(2, 3), frac(4, 5)]), Ok(2));
+        assert_eq!(
+            numerators_gcd(&[frac(6, 4), frac(9, 2), frac(-15, 8)]),
+            Ok(3)
+        );
+        assert_eq!(numerators_gcd(&[frac(1, 2), frac(1, 3)]), Ok(1));
+        assert_eq!(numerators_gcd(&[frac(0, 5), frac(8, 3)]), Ok(8));
+        assert_eq!(numerators_gcd(&[]), Ok(0));
+
+        let min = Fraction::from(i64::MIN);
+        assert_eq!(numerators_gcd(&[min]), Err(FractionError::Overflow));
+        assert_eq!(
+            numerators_gcd(&[min, frac(0, 1), min]),
+            Err(FractionError::Overflow)
+        );
+        assert_eq!(numerators_gcd(&[min, frac(6, 1)]), Ok(2));
+    }
+
+    #[test]
+    fn test_parallel_combine() {
+        assert_eq!(parallel_combine(&[frac(2, 1), frac(2, 1)]), Ok(frac(1, 1)));
+        assert_eq!(parallel_combine(&[frac(3, 1), frac(6, 1)]), Ok(frac(2, 1)));
+        assert_eq!(
+            parallel_combine(&[frac(1, 2), frac(1, 3), frac(1, 6)]),
+            Ok(frac(1, 11))
+        );
+        assert_eq!(parallel_combine(&[frac(5, 2)]), Ok(frac(5, 2)));
+
+        assert_eq!(
+            parallel_combine(&[frac(2, 1), frac(0, 1)]),
+            Err(FractionError::DivisionByZero)
+        );
+        assert_eq!(
+            parallel_combine(&[frac(2, 1), frac(-2, 1)]),
+            Err(FractionError::DivisionByZero)
+        );
+        assert_eq!(parallel_combine(&[]), Err(FractionError::DivisionByZero));
+    }
+
+    #[test]
+    fn test_superscript_string() {
+        assert_eq!(frac(3, 4).to_superscript_string(), "³⁄₄");
+        assert_eq!(frac(-10, 16).to_superscript_string(), "-⁵⁄₈");
+        assert_eq!(frac(127, 90).to_superscript_string(), "¹²⁷⁄₉₀");
+        assert_eq!(frac(6, 3).to_superscript_string(), "2");
+    }
+
+    #[test]
+    fn test_wrap_into_range() {
+        let (zero, one) = (frac(0, 1), frac(1, 1));
+
+        assert_eq!(frac(5, 2).wrap_into_range(&zero, &one), Ok(frac(1, 2)));
+        assert_eq!(frac(-1, 4).wrap_into_range(&zero, &one), Ok(frac(3, 4)));
+        assert_eq!(frac(1, 1).wrap_into_range(&zero, &one), Ok(frac(0, 1)));
+        assert_eq!(frac(1, 3).wrap_into_range(&zero, &one), Ok(frac(1, 3)));
+        // Degrees wrapped into [-180, 180)
+        assert_eq!(
+            frac(190, 1).wrap_into_range(&frac(-180, 1), &frac(180, 1)),
+            Ok(frac(-170, 1))
+        );
+        assert_eq!(
+            frac(1, 2).wrap_into_range(&one, &one),
+            Err(FractionError::InvalidArgument)
+        );
+        assert_eq!(
+            frac(1, 2).wrap_into_range(&one, &zero),
+            Err(FractionError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn test_binomial_weighted_sum() {
+        let values = [frac(1, 2), frac(1, 3), frac(1, 4)];
+        assert_eq!(binomial_weighted_sum(&values), Ok(frac(17, 12)));
+
+        // 1*1 + 3*(1/2) + 3*(-1/3) + 1*2
+        let cubic = [frac(1, 1), frac(1, 2), frac(-1, 3), frac(2, 1)];
+        assert_eq!(binomial_weighted_sum(&cubic), Ok(frac(7, 2)));
+
+        // Unit values sum the row of Pascal's triangle: 2^n
+        let ones = [frac(1, 1); 11];
+        assert_eq!(binomial_weighted_sum(&ones), Ok(frac(1024, 1)));
+
+        assert_eq!(binomial_weighted_sum(&[]), Ok(frac(0, 1)));
+        assert_eq!(
+            binomial_weighted_sum(&[frac(1, 1); 80]),
+            Err(FractionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_approximate_constants() {
+        assert_eq!(Fraction::approximate_pi(1), frac(3, 1));
+        assert_eq!(Fraction::approximate_pi(7), frac(22, 7));
+        assert_eq!(Fraction::approximate_pi(200), frac(355, 113));
+        assert_eq!(Fraction::approximate_pi(40_000), frac(104348, 33215));
+        assert_eq!(Fraction::approximate_pi(0), frac(3, 1));
+
+        assert_eq!(Fraction::approximate_e(10), frac(19, 7));
+        assert_eq!(Fraction::approximate_e(100), frac(193, 71));
+        assert_eq!(Fraction::approximate_e(1000), frac(1457, 536));
+
+        let pi = Fraction::approximate_pi(i64::MAX);
+        assert!((pi.to_f64() - std::f64::consts::PI).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_scale_to_sum() {
+        assert_eq!(
+            scale_to_sum(&[frac(1, 2), frac(1, 2)], &frac(3, 1)),
+            Ok(vec![frac(3, 2), frac(3, 2)])
+        );
+
+        let budget = scale_to_sum(&[frac(1, 1), frac(2, 1), frac(3, 1)], &frac(1, 2)).unwrap();
+        assert_eq!(budget, vec![frac(1, 12), frac(1, 6), frac(1, 4)]);
+        assert_eq!(budget.iter().fold(frac(0, 1), |a, &b| a + b), frac(1, 2));
+
+        assert_eq!(
+            scale_to_sum(&[frac(1, 2), frac(-1, 2)], &frac(1, 1)),
+            Err(FractionError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_stern_brocot_depth() {
+        assert_eq!(frac(1, 1).stern_brocot_depth(), Ok(0));
+        assert_eq!(frac(1, 2).stern_brocot_depth(), Ok(1));
+        assert_eq!(frac(2, 1).stern_brocot_depth(), Ok(1));
+        assert_eq!(frac(2, 3).stern_brocot_depth(), Ok(2));
+        assert_eq!(frac(6, 9).stern_brocot_depth(), Ok(2));
+        // 355/113 = [3; 7, 16]
+        assert_eq!(frac(355, 113).stern_brocot_depth(), Ok(25));
+
+        assert_eq!(
+            frac(0, 1).stern_brocot_depth(),
+            Err(FractionError::InvalidArgument)
+        );
+        assert_eq!(
+            frac(-1, 2).stern_brocot_depth(),
+            Err(FractionError::InvalidArgument)
+        );
+        assert_eq!(
+            frac(1, i64::MAX).stern_brocot_depth(),
+            Err(FractionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_piecewise_linear() {
+        let table = [
+            (frac(0, 1), frac(1, 1)),
+            (frac(1, 2), frac(2, 1)),
+            (frac(3, 2), frac(1, 2)),
+        ];
+
+        assert_eq!(piecewise_linear(&table, &frac(1, 4)), Ok(frac(3, 2)));
+        assert_eq!(piecewise_linear(&table, &frac(1, 1)), Ok(frac(5, 4)));
+        assert_eq!(piecewise_linear(&table, &frac(0, 1)), Ok(frac(1, 1)));
+        assert_eq!(piecewise_linear(&table, &frac(3, 2)), Ok(frac(1, 2)));
+
+        assert_eq!(
+            piecewise_linear(&table, &frac(2, 1)),
+            Err(FractionError::InvalidArgument)
+        );
+        assert_eq!(
+            piecewise_linear(&table, &frac(-1, 2)),
+            Err(FractionError::InvalidArgument)
+        );
+        let unsorted = [(frac(1, 1), frac(0, 1)), (frac(1, 2), frac(1, 1))];
+        assert_eq!(
+            piecewise_linear(&unsorted, &frac(3, 4)),
+            Err(FractionError::InvalidArgument)
+        );
+        assert_eq!(
+            piecewise_linear(&[], &frac(0, 1)),
+            Err(FractionError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn test_to_sexagesimal() {
+        assert_eq!(frac(3, 2).to_sexagesimal(1), "1;30");
+        assert_eq!(frac(3, 2).to_sexagesimal(3), "1;30,0,0");
+        assert_eq!(frac(1, 3).to_sexagesimal(2), "0;20,0");
+        assert_eq!(frac(1, 8).to_sexagesimal(2), "0;7,30");
+        assert_eq!(frac(-7, 3).to_sexagesimal(1), "-2;20");
+        assert_eq!(frac(7, 3).to_sexagesimal(0), "2");
+    }
+
+    #[test]
+    fn test_round_with() {
+        use RoundingMode::*;
+        let (five_halves, seven_halves) = (frac(5, 2), frac(7, 2));
+
+        let expected = [
+            (Floor, 2, 3, -3, -4),
+            (Ceiling, 3, 4, -2, -3),
+            (TowardZero, 2, 3, -2, -3),
+            (AwayFromZero, 3, 4, -3, -4),
+            (HalfUp, 3, 4, -2, -3),
+            (HalfDown, 2, 3, -3, -4),
+            (HalfTowardZero, 2, 3, -2, -3),
+            (HalfAwayFromZero, 3, 4, -3, -4),
+            (HalfToEven, 2, 4, -2, -4),
+            (HalfToOdd, 3, 3, -3, -3),
+        ];
+        for (mode, a, b, c, d) in expected {
+            assert_eq!(five_halves.round_with(mode), a, "{mode:?}");
+            assert_eq!(seven_halves.round_with(mode), b, "{mode:?}");
+            assert_eq!((-five_halves).round_with(mode), c, "{mode:?}");
+            assert_eq!((-seven_halves).round_with(mode), d, "{mode:?}");
+        }
+
+        // Away from the halfway point every nearest mode agrees
+        for mode in [
+            HalfUp,
+            HalfDown,
+            HalfTowardZero,
+            HalfAwayFromZero,
+            HalfToEven,
+            HalfToOdd,
+        ] {
+            assert_eq!(frac(8, 3).round_with(mode), 3);
+            assert_eq!(frac(-7, 3).round_with(mode), -2);
+            assert_eq!(frac(6, 3).round_with(mode), 2);
+        }
+    }
+
+    #[test]
+    fn test_decimal_string() {
+        assert_eq!(frac(1, 3).to_decimal_string(), "0.(3)");
+        assert_eq!(frac(1, 6).to_decimal_string(), "0.1(6)");
+        assert_eq!(frac(1, 4).to_decimal_string(), "0.25");
+        assert_eq!(frac(-22, 7).to_decimal_string(), "-3.(142857)");
+        assert_eq!(frac(1, 12).to_decimal_string(), "0.08(3)");
+        assert_eq!(frac(3, 700).to_decimal_string(), "0.00(428571)");
+        assert_eq!(frac(10, 5).to_decimal_string(), "2");
+        assert_eq!(frac(0, 5).to_decimal_string(), "0");
+
+        // The period of 1/(2^63 - 1) is far too long to write out
+        let long = frac(1, i64::MAX).to_decimal_string();
+        assert!(long.starts_with("0.(000000000000000000108420217"));
+        assert!(long.ends_with("...)"));
+        assert_eq!(long.len(), "0.(...)".len() + DECIMAL_DIGIT_LIMIT);
+
+        // 1/(2^10 * 4127) has 10 digits before a 4126-digit period
+        let mixed = frac(1, 1024 * 4127).to_decimal_string();
+        assert!(mixed.starts_with("0.0000002366("));
+        assert_eq!(mixed.len(), "0.(...)".len() + DECIMAL_DIGIT_LIMIT);
+    }
+
+    #[test]
+    fn test_decimal_string_locale() {
+        assert_eq!(frac(7, 6).to_decimal_string_locale(','), "1,1(6)");
+        assert_eq!(frac(-5, 4).to_decimal_string_locale(','), "-1,25");
+        assert_eq!(frac(2, 3).to_decimal_string_locale('·'), "0·(6)");
+        assert_eq!(frac(3, 1).to_decimal_string_locale(','), "3");
+        assert!(
+            frac(-1, i64::MAX)
+                .to_decimal_string_locale(',')
+                .starts_with("-0,(0000")
+        );
+    }
+
+    #[test]
+    fn test_clamp_and_quantize() {
+        let (lo, hi, step) = (frac(0, 1), frac(1, 1), frac(1, 4));
+
+        assert_eq!(
+            frac(3, 2).clamp_and_quantize(&lo, &hi, &step),
+            Ok(frac(1, 1))
+        );
+        assert_eq!(
+            frac(-3, 2).clamp_and_quantize(&lo, &hi, &step),
+            Ok(frac(0, 1))
+        );
+        assert_eq!(
+            frac(3, 10).clamp_and_quantize(&lo, &hi, &step),
+            Ok(frac(1, 4))
+        );
+        assert_eq!(
+            frac(5, 8).clamp_and_quantize(&lo, &hi, &step),
+            Ok(frac(3, 4))
+        );
+        assert_eq!(
+            frac(2, 3).clamp_and_quantize(&lo, &hi, &frac(-1, 4)),
+            Ok(frac(3, 4))
+        );
+
+        // Nearest multiple of 1/3 to the upper bound 9/10 lies above it
+        let third = frac(1, 3);
+        assert_eq!(
+            frac(2, 1).clamp_and_quantize(&lo, &frac(9, 10), &third),
+            Ok(frac(2, 3))
+        );
+
+        assert_eq!(
+            frac(1, 2).clamp_and_quantize(&lo, &hi, &frac(0, 1)),
+            Err(FractionError::DivisionByZero)
+        );
+        assert_eq!(
+            frac(1, 2).clamp_and_quantize(&hi, &lo, &step),
+            Err(FractionError::InvalidArgument)
+        );
+        assert_eq!(
+            frac(1, 2).clamp_and_quantize(&frac(1, 10), &frac(2, 10), &step),
+            Err(FractionError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn test_dyadic_parts() {
+        let exact = frac(-5, 8);
+        let q16 = exact.to_dyadic_parts(16).unwrap();
+        assert_eq!(q16, -40960);
+        assert_eq!(Fraction::from_dyadic_parts(q16, 16), Ok(exact));
+
+        let third = frac(1, 3);
+        let q16 = third.to_dyadic_parts(16).unwrap();
+        assert_eq!(q16, 21845);
+        let back = Fraction::from_dyadic_parts(q16, 16).unwrap();
+        assert_eq!(back.denominator(), 65536);
+        assert!((back - third).abs() <= frac(1, 1 << 17));
+        assert_eq!(back.to_dyadic_parts(16), Some(q16));
+
+        assert_eq!(Fraction::from_dyadic_parts(1 << 20, 16), Ok(frac(16, 1)));
+        assert_eq!(
+            Fraction::from_dyadic_parts(i64::MIN, 0),
+            Ok(Fraction::from(i64::MIN))
+        );
+        assert_eq!(Fraction::from_dyadic_parts(i64::MIN, 63), Ok(frac(-1, 1)));
+        assert_eq!(Fraction::from_dyadic_parts(i64::MIN, 70), Ok(frac(-1, 128)));
+        assert_eq!(Fraction::from_dyadic_parts(0, u32::MAX), Ok(frac(0, 1)));
+        assert_eq!(
+            Fraction::from_dyadic_parts(1, 63),
+            Err(FractionError::Overflow)
+        );
+        assert_eq!(frac(1, 1).to_dyadic_parts(63), None);
+        assert_eq!(frac(1, 1 << 62).to_dyadic_parts(100), Some(1 << 38));
+        assert_eq!(frac(0, 1).to_dyadic_parts(200), Some(0));
+        assert_eq!(frac(1, 2).to_dyadic_parts(u32::MAX), None);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("3/4".parse::<Fraction>(), Ok(frac(3, 4)));
+        assert_eq!("−3/4".parse::<Fraction>(), Ok(frac(-3, 4)));
+        assert_eq!("-3/4".parse::<Fraction>(), Ok(frac(-3, 4)));
+        assert_eq!("3/-4".parse::<Fraction>(), Ok(frac(-3, 4)));
+        assert_eq!("0/5".parse::<Fraction>(), Ok(frac(0, 1)));
+        assert_eq!("  7  ".parse::<Fraction>(), Ok(frac(7, 1)));
+        assert_eq!("-5".parse::<Fraction>(), Ok(frac(-5, 1)));
+
+        assert_eq!(
+            "1/0".parse::<Fraction>(),
+            Err(FractionError::ZeroDenominator)
+        );
+        assert_eq!(
+            "99999999999999999999/2".parse::<Fraction>(),
+            Err(FractionError::Overflow)
+        );
+        for bad in [
+            "1/2/3", "abc", "", "  ", "/", "1/", "/2", "--3/4", "+3/4", "3 / 4", "1.5",
+        ] {
+            assert_eq!(
+                bad.parse::<Fraction>(),
+                Err(FractionError::ParseError),
+                "{:?}",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_integer_types() {
+        assert_eq!(Fraction::from(5i32), Fraction::from_integer(5));
+        assert_eq!(Fraction::from(-5i32), Fraction::from_integer(-5));
+        assert_eq!(
+            Fraction::from(u32::MAX),
+            Fraction::from_integer(u32::MAX as i64)
+        );
+        assert_eq!(Fraction::from(7u8), Fraction::from_integer(7));
+
+        let min = Fraction::from(i64::MIN);
+        assert_eq!(min.numerator(), i64::MIN);
+        assert_eq!(min.denominator(), 1);
+    }
+
+    #[test]
+    fn test_reduction_info() {
+        let f = |n, d| Fraction::new_unreduced(n, d).unwrap();
+
+        assert_eq!(f(12, 8).reduction_info(), (true, 4));
+        assert_eq!(f(3, 2).reduction_info(), (false, 1));
+        assert_eq!(f(-12, 8).reduction_info(), (true, 4));
+        assert_eq!(f(0, 5).reduction_info(), (true, 5));
+        assert_eq!(f(0, 1).reduction_info(), (false, 1));
+    }
+
+    #[test]
+    fn test_nearest_unit_fraction() {
+        assert_eq!(frac(3, 10).nearest_unit_fraction(), Ok(frac(1, 3)));
+        assert_eq!(frac(1, 7).nearest_unit_fraction(), Ok(frac(1, 7)));
+        assert_eq!(frac(2, 9).nearest_unit_fraction(), Ok(frac(1, 5)));
+        assert_eq!(frac(2, 5).nearest_unit_fraction(), Ok(frac(1, 3)));
+        assert_eq!(frac(2, 3).nearest_unit_fraction(), Ok(frac(1, 2)));
+        assert_eq!(frac(5, 1).nearest_unit_fraction(), Ok(frac(1, 1)));
+        assert_eq!(frac(69, 100).nearest_unit_fraction(), Ok(frac(1, 2)));
+        assert_eq!(frac(3, 4).nearest_unit_fraction(), Ok(frac(1, 2)));
+        assert_eq!(frac(1, 1).nearest_unit_fraction(), Ok(frac(1, 1)));
+        assert_eq!(
+            frac(1, i64::MAX).nearest_unit_fraction(),
+            Ok(frac(1, i64::MAX))
+        );
+        assert_eq!(
+            frac(2, i64::MAX).nearest_unit_fraction(),
+            Ok(frac(1, i64::MAX / 2 + 1))
+        );
+        assert_eq!(
+            frac(0, 1).nearest_unit_fraction(),
+            Err(FractionError::InvalidArgument)
+        );
+        assert_eq!(
+            frac(-1, 3).nearest_unit_fraction(),
+            Err(FractionError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        assert_eq!(frac(1, 6).checked_add(&frac(1, 3)), Ok(frac(1, 2)));
+        assert_eq!(frac(1, 2).checked_sub(&frac(3, 4)), Ok(frac(-1, 4)));
+        assert_eq!(frac(6, 10).checked_mul(&frac(10, 6)), Ok(frac(1, 1)));
+        assert_eq!(frac(1, 2).checked_div(&frac(-1, 4)), Ok(frac(-2, 1)));
+        assert_eq!(
+            frac(1, 2).checked_div(&frac(0, 1)),
+            Err(FractionError::DivisionByZero)
+        );
+
+        let sum = frac(1, 1_000_000_000)
+            .checked_add(&frac(1, 1_000_000_001))
+            .unwrap();
+        assert_eq!(sum.numerator(), 2_000_000_001);
+        assert_eq!(sum.denominator(), 1_000_000_001_000_000_000);
+
+        let max = Fraction::from_integer(i64::MAX);
+        assert_eq!(max.checked_add(&frac(1, 1)), Err(FractionError::Overflow));
+        assert_eq!(
+            frac(i64::MIN, 1).checked_sub(&frac(1, 1)),
+            Err(FractionError::Overflow)
+        );
+        assert_eq!(max.checked_mul(&frac(2, 1)), Err(FractionError::Overflow));
+        assert_eq!(max.checked_div(&frac(1, 2)), Err(FractionError::Overflow));
+        assert_eq!(
+            frac(1, i64::MAX).checked_add(&frac(1, i64::MAX - 1)),
+            Err(FractionError::Overflow)
+        );
+
+        // Results that fit after reduction are not reported as overflow
+        assert_eq!(max.checked_mul(&frac(2, i64::MAX)), Ok(frac(2, 1)));
+    }
+
+    #[test]
+    fn test_split_equally() {
+        let thirds = frac(1, 1).split_equally(3).unwrap();
+        assert_eq!(thirds, vec![frac(1, 3); 3]);
+        let total = thirds
+            .iter()
+            .try_fold(frac(0, 1), |acc, p| acc.checked_add(p));
+        assert_eq!(total, Ok(frac(1, 1)));
+
+        assert_eq!(frac(-2, 5).split_equally(1), Ok(vec![frac(-2, 5)]));
+        assert_eq!(
+            frac(1, 1).split_equally(0),
+            Err(FractionError::InvalidArgument)
+        );
+        assert_eq!(
+            frac(1, i64::MAX).split_equally(2),
+            Err(FractionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_arithmetic_cross_reduces() {
+        let product = frac(6, 10) * frac(10, 6);
+        assert_eq!((product.numerator(), product.denominator()), (1, 1));
+        let quotient = (frac(3, 4) / frac(-9, 8)).unwrap();
+        assert_eq!((quotient.numerator(), quotient.denominator()), (-2, 3));
+        let sum = frac(1, 6) + frac(1, 3);
+        assert_eq!((sum.numerator(), sum.denominator()), (1, 2));
+        let difference = frac(5, 12).subtract(&frac(1, 12));
+        assert_eq!((difference.numerator(), difference.denominator()), (1, 3));
 
-    fn neg(self) -> Self::Output {
-        Self {
-            numerator: -self.numerator,
-            denominator: self.denominator,
+        let harmonic = (1..=20).fold(Fraction::from_integer(0), |acc, n| acc + frac(1, n));
+        assert_eq!(harmonic.numerator(), 55_835_135);
+        assert_eq!(harmonic.denominator(), 15_519_504);
+
+        // Without intermediate reduction this product would overflow
+        let big = frac(1 << 40, 3);
+        let tiny = frac(3, 1 << 40);
+        assert_eq!(big * tiny, frac(1, 1));
+
+        // Intermediates are i128, so only the final narrowing can fail
+        let min = Fraction::from(i64::MIN);
+        assert_eq!(frac(-1, 1) - min, frac(i64::MAX, 1));
+        assert_eq!(min / min, Ok(frac(1, 1)));
+        assert_eq!(frac(0, 1) / min, Ok(frac(0, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "fraction arithmetic overflowed i64")]
+    fn test_arithmetic_overflow_panics() {
+        let _ = frac(i64::MAX, 1) + frac(1, 1);
+    }
+
+    #[test]
+    fn test_distribute() {
+        let shares = frac(1, 1).distribute(3, 100).unwrap();
+        assert_eq!(shares, vec![frac(34, 100), frac(33, 100), frac(33, 100)]);
+        let total = shares.iter().fold(frac(0, 1), |acc, &p| acc + p);
+        assert_eq!(total, frac(1, 1));
+
+        // 2/3 rounds to 67 cents before splitting
+        let shares = frac(2, 3).distribute(2, 100).unwrap();
+        assert_eq!(shares, vec![frac(34, 100), frac(33, 100)]);
+
+        let shares = frac(-1, 1).distribute(3, 100).unwrap();
+        assert_eq!(shares, vec![frac(-33, 100), frac(-33, 100), frac(-34, 100)]);
+
+        assert_eq!(
+            frac(1, 1).distribute(0, 100),
+            Err(FractionError::InvalidArgument)
+        );
+        assert_eq!(
+            frac(1, 1).distribute(3, 0),
+            Err(FractionError::InvalidArgument)
+        );
+        assert_eq!(
+            frac(i64::MAX, 1).distribute(3, 2),
+            Err(FractionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_hash_consistent_with_eq() {
+        use std::collections::HashMap;
+
+        let f = |n, d| Fraction::new_unreduced(n, d).unwrap();
+
+        let mut set = HashSet::new();
+        set.insert(Fraction::new(1, 2).unwrap());
+        assert!(set.contains(&Fraction::new(2, 4).unwrap()));
+        assert!(set.contains(&f(2, 4)));
+        assert!(set.contains(&(f(3, 4) / f(-3, -2)).unwrap()));
+        assert!(!set.contains(&f(-1, 2)));
+
+        let mut counts = HashMap::new();
+        for frac in [f(1, 3), f(2, 6), f(-1, 3), f(3, 9)] {
+            *counts.entry(frac).or_insert(0) += 1;
         }
+        assert_eq!(counts[&f(1, 3)], 3);
+        assert_eq!(counts[&f(-2, 6)], 1);
     }
-}
 
-impl From<i64> for Fraction {
-    fn from(n: i64) -> Self {
-        Self::from_integer(n)
+    #[test]
+    fn test_harmonic_number() {
+        assert_eq!(Fraction::harmonic_number(0), Ok(frac(0, 1)));
+        assert_eq!(Fraction::harmonic_number(1), Ok(frac(1, 1)));
+        assert_eq!(Fraction::harmonic_number(4), Ok(frac(25, 12)));
+
+        let h20 = Fraction::harmonic_number(20).unwrap();
+        assert_eq!(
+            (h20.numerator(), h20.denominator()),
+            (55_835_135, 15_519_504)
+        );
+
+        assert!(Fraction::harmonic_number(46).is_ok());
+        assert_eq!(Fraction::harmonic_number(47), Err(FractionError::Overflow));
+        assert_eq!(
+            Fraction::harmonic_number(1000),
+            Err(FractionError::Overflow)
+        );
     }
-}
 
-impl TryFrom<(i64, i64)> for Fraction {
-    type Error = FractionError;
+    #[test]
+    fn test_pow() {
+        assert_eq!(frac(2, 3).pow(0), Ok(frac(1, 1)));
+        assert_eq!(frac(2, 3).pow(3), Ok(frac(8, 27)));
+        assert_eq!(frac(2, 3).pow(-2), Ok(frac(9, 4)));
+        assert_eq!(frac(-2, 3).pow(-3), Ok(frac(-27, 8)));
+        assert_eq!(frac(0, 1).pow(0), Ok(frac(1, 1)));
+        assert_eq!(frac(0, 1).pow(-1), Err(FractionError::DivisionByZero));
 
-    fn try_from(value: (i64, i64)) -> Result<Self, Self::Error> {
-        Self::new(value.0, value.1)
+        let raw = Fraction::new_unreduced(4, 6).unwrap().pow(10).unwrap();
+        assert_eq!((raw.numerator(), raw.denominator()), (1024, 59049));
+
+        assert_eq!(frac(-1, 1).pow(i32::MIN), Ok(frac(1, 1)));
+        assert_eq!(frac(1, 2).pow(62), Ok(frac(1, 1 << 62)));
+        assert_eq!(frac(1, 2).pow(63), Err(FractionError::Overflow));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_factorial_ratio() {
+        assert_eq!(Fraction::factorial_ratio(5, 3), Ok(frac(20, 1)));
+        assert_eq!(Fraction::factorial_ratio(3, 5), Ok(frac(1, 20)));
+        assert_eq!(Fraction::factorial_ratio(7, 7), Ok(frac(1, 1)));
+        assert_eq!(Fraction::factorial_ratio(0, 0), Ok(frac(1, 1)));
+        assert_eq!(
+            Fraction::factorial_ratio(20, 0),
+            Ok(frac(2_432_902_008_176_640_000, 1))
+        );
+        assert_eq!(Fraction::factorial_ratio(100, 98), Ok(frac(9900, 1)));
+        assert_eq!(
+            Fraction::factorial_ratio(21, 0),
+            Err(FractionError::Overflow)
+        );
+        assert_eq!(
+            Fraction::factorial_ratio(0, 21),
+            Err(FractionError::Overflow)
+        );
+    }
 
     #[test]
-    fn test_new() {
-        assert!(Fraction::new(1, 2).is_ok());
-        assert_eq!(Fraction::new(1, 0), Err(FractionError::ZeroDenominator));
+    fn test_floor_ceil_trunc_round() {
+        let cases = [
+            // value, floor, ceil, trunc, round
+            (frac(7, 2), 3, 4, 3, 4),
+            (frac(-7, 2), -4, -3, -3, -4),
+            (frac(5, 3), 1, 2, 1, 2),
+            (frac(-5, 3), -2, -1, -1, -2),
+            (frac(1, 3), 0, 1, 0, 0),
+            (frac(-1, 3), -1, 0, 0, 0),
+            (frac(6, 1), 6, 6, 6, 6),
+            (frac(-6, 1), -6, -6, -6, -6),
+            (frac(0, 1), 0, 0, 0, 0),
+        ];
+        for (value, floor, ceil, trunc, round) in cases {
+            assert_eq!(value.floor(), floor, "{}", value);
+            assert_eq!(value.ceil(), ceil, "{}", value);
+            assert_eq!(value.trunc(), trunc, "{}", value);
+            assert_eq!(value.round(), round, "{}", value);
+        }
+
+        let huge = frac(i64::MAX, 2);
+        assert_eq!(huge.floor(), i64::MAX / 2);
+        assert_eq!(huge.ceil(), i64::MAX / 2 + 1);
+        assert_eq!(frac(i64::MIN, 1).floor(), i64::MIN);
     }
 
     #[test]
-    fn test_negative_denominator() {
-        let frac = Fraction::new(1, -2).unwrap();
-        assert_eq!(frac.numerator, -1);
-        assert_eq!(frac.denominator, 2);
+    fn test_lower_bound() {
+        let values = [
+            frac(i64::MIN + 1, 3),
+            frac(-1, 2),
+            frac(0, 1),
+            frac(1, i64::MAX),
+            frac(1, i64::MAX - 1),
+            frac(1, 2),
+            frac(i64::MAX - 1, 2),
+            frac(i64::MAX, 2),
+        ];
+        assert_eq!(lower_bound(&values, &frac(i64::MIN, 1)), 0);
+        assert_eq!(lower_bound(&values, &frac(-1, 2)), 1);
+        assert_eq!(lower_bound(&values, &frac(1, i64::MAX)), 3);
+        assert_eq!(lower_bound(&values, &frac(1, i64::MAX - 2)), 5);
+        assert_eq!(lower_bound(&values, &frac(i64::MAX, 3)), 6);
+        assert_eq!(lower_bound(&values, &frac(i64::MAX, 2)), 7);
+        assert_eq!(lower_bound(&values, &frac(i64::MAX, 1)), 8);
+        assert_eq!(lower_bound(&[], &frac(0, 1)), 0);
     }
 
     #[test]
-    fn test_reduce() {
-        let frac = Fraction::new(12, 8).unwrap();
-        let reduced = frac.reduce();
-        assert_eq!(reduced.numerator, 3);
-        assert_eq!(reduced.denominator, 2);
+    fn test_rem() {
+        assert_eq!(frac(7, 2) % frac(1, 3), Ok(frac(1, 6)));
+        assert_eq!(frac(-7, 2) % frac(1, 3), Ok(frac(-1, 6)));
+        assert_eq!(frac(7, 2) % frac(-1, 3), Ok(frac(1, 6)));
+        assert_eq!(frac(-7, 2) % frac(-1, 3), Ok(frac(-1, 6)));
+        assert_eq!(Fraction::rem(&frac(3, 2), &frac(1, 2)), Ok(frac(0, 1)));
+        assert_eq!(Fraction::rem(&frac(1, 4), &frac(3, 2)), Ok(frac(1, 4)));
+        assert_eq!(frac(7, 1) % frac(3, 1), Ok(frac(7 % 3, 1)));
+        assert_eq!(frac(-7, 1) % frac(3, 1), Ok(frac(-7 % 3, 1)));
+        assert_eq!(frac(1, 2) % frac(0, 1), Err(FractionError::DivisionByZero));
     }
 
     #[test]
-    fn test_display() {
-        assert_eq!(Fraction::new(3, 4).unwrap().to_string(), "3/4");
-        assert_eq!(Fraction::new(4, 2).unwrap().to_string(), "2");
-        assert_eq!(Fraction::new(-3, 4).unwrap().to_string(), "-3/4");
+    fn test_format_pair() {
+        let pair = |a: Fraction, b: Fraction| a.format_pair(&b);
+
+        assert_eq!(
+            pair(frac(1, 2), frac(1, 3)),
+            ("3/6".to_string(), "2/6".to_string())
+        );
+        assert_eq!(
+            pair(frac(-1, 4), frac(5, 6)),
+            ("-3/12".to_string(), "10/12".to_string())
+        );
+        assert_eq!(
+            pair(frac(2, 1), frac(1, 3)),
+            ("6/3".to_string(), "1/3".to_string())
+        );
+        assert_eq!(
+            pair(frac(2, 4), frac(1, 2)),
+            ("1/2".to_string(), "1/2".to_string())
+        );
+        assert_eq!(
+            pair(frac(1, i64::MAX), frac(1, i64::MAX - 1)),
+            (
+                format!(
+                    "{}/{}",
+                    i64::MAX - 1,
+                    (i64::MAX as i128) * (i64::MAX as i128 - 1)
+                ),
+                format!(
+                    "{}/{}",
+                    i64::MAX,
+                    (i64::MAX as i128) * (i64::MAX as i128 - 1)
+                ),
+            )
+        );
     }
 
     #[test]
-    fn test_arithmetic() {
-        let half = Fraction::new(1, 2).unwrap();
-        let third = Fraction::new(1, 3).unwrap();
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(12, -18), 6);
+        assert_eq!(gcd(-12, -18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd(-7, 0), 7);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(i64::MIN, 6), 2);
 
-        let sum = half.add(third).reduce();
-        assert_eq!(sum.numerator, 5);
-        assert_eq!(sum.denominator, 6);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(-4, 6), 12);
+        assert_eq!(lcm(-4, -6), 12);
+        assert_eq!(lcm(0, 5), 0);
+        assert_eq!(lcm(5, 0), 0);
+        assert_eq!(lcm(0, 0), 0);
 
-        let diff = half.subtract(&third).reduce();
-        assert_eq!(diff.numerator, 1);
-        assert_eq!(diff.denominator, 6);
+        // a * b would overflow, a / gcd * b does not
+        let a: i64 = 3 << 40;
+        let b = 5 << 40;
+        assert!(a.checked_mul(b).is_none());
+        assert_eq!(lcm(a, b), 15 << 40);
 
-        let product = half.multiply(&third).reduce();
-        assert_eq!(product.numerator, 1);
-        assert_eq!(product.denominator, 6);
+        assert_eq!(checked_lcm(-4, 6), Ok(12));
+        assert_eq!(checked_lcm(0, i64::MIN), Ok(0));
+        assert_eq!(checked_lcm(i64::MIN, 2), Err(FractionError::Overflow));
+        assert_eq!(
+            checked_lcm(i64::MIN, i64::MIN),
+            Err(FractionError::Overflow)
+        );
+        assert_eq!(
+            checked_lcm(i64::MAX, i64::MAX - 1),
+            Err(FractionError::Overflow)
+        );
+    }
 
-        let quotient = half.divide(&third).unwrap().reduce();
-        assert_eq!(quotient.numerator, 3);
-        assert_eq!(quotient.denominator, 2);
+    #[test]
+    fn test_clamp_and_renormalize() {
+        let mut probs = [frac(1, 4), frac(5, 4), frac(-1, 2), frac(1, 4)];
+        clamp_and_renormalize(&mut probs).unwrap();
+        assert_eq!(probs, [frac(1, 6), frac(2, 3), frac(0, 1), frac(1, 6)]);
+        let total = probs.iter().fold(frac(0, 1), |acc, &p| acc + p);
+        assert_eq!(total, frac(1, 1));
+
+        let mut valid = [frac(1, 3), frac(2, 3)];
+        clamp_and_renormalize(&mut valid).unwrap();
+        assert_eq!(valid, [frac(1, 3), frac(2, 3)]);
+
+        let mut negative = [frac(-1, 2), frac(0, 1)];
+        assert_eq!(
+            clamp_and_renormalize(&mut negative),
+            Err(FractionError::DivisionByZero)
+        );
+        assert_eq!(negative, [frac(-1, 2), frac(0, 1)]);
+        assert_eq!(
+            clamp_and_renormalize(&mut []),
+            Err(FractionError::DivisionByZero)
+        );
     }
 
     #[test]
-    fn test_operators() {
-        let half = Fraction::new(1, 2).unwrap();
-        let third = Fraction::new(1, 3).unwrap();
+    fn test_sqrt_continued_fraction() {
+        assert_eq!(sqrt_continued_fraction(2, 6), Ok(vec![1, 2, 2, 2, 2, 2]));
+        assert_eq!(sqrt_continued_fraction(3, 5), Ok(vec![1, 1, 2, 1, 2]));
+        assert_eq!(sqrt_continued_fraction(23, 6), Ok(vec![4, 1, 3, 1, 8, 1]));
+        assert_eq!(sqrt_continued_fraction(2, 0), Ok(vec![]));
 
-        let sum = (half + third).reduce();
-        assert_eq!(sum.numerator, 5);
-        assert_eq!(sum.denominator, 6);
+        let big = sqrt_continued_fraction(i64::MAX, 3).unwrap();
+        assert_eq!(big[0], 3_037_000_499);
 
-        let diff = (half - third).reduce();
-        assert_eq!(diff.numerator, 1);
-        assert_eq!(diff.denominator, 6);
+        assert_eq!(
+            sqrt_continued_fraction(16, 3),
+            Err(FractionError::InvalidArgument)
+        );
+        assert_eq!(
+            sqrt_continued_fraction(0, 3),
+            Err(FractionError::InvalidArgument)
+        );
+        assert_eq!(
+            sqrt_continued_fraction(-2, 3),
+            Err(FractionError::InvalidArgument)
+        );
+    }
 
-        let product = (half * third).reduce();
-        assert_eq!(product.numerator, 1);
-        assert_eq!(product.denominator, 6);
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        for value in [frac(3, 4), frac(-6, 8), frac(5, 1), frac(0, 1)] {
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(serde_json::from_str::<Fraction>(&json).unwrap(), value);
+        }
+        assert_eq!(serde_json::to_string(&frac(6, 8)).unwrap(), r#""3/4""#);
+        assert_eq!(serde_json::to_string(&frac(-5, 1)).unwrap(), r#""-5""#);
 
-        let quotient = (half / third).unwrap().reduce();
-        assert_eq!(quotient.numerator, 3);
-        assert_eq!(quotient.denominator, 2);
+        let list: Vec<Fraction> = serde_json::from_str(r#"["1/2", " 2 ", "-1/3"]"#).unwrap();
+        assert_eq!(list, vec![frac(1, 2), frac(2, 1), frac(-1, 3)]);
 
-        let neg = -half;
-        assert_eq!(neg.numerator, -1);
-        assert_eq!(neg.denominator, 2);
+        let zero = serde_json::from_str::<Fraction>(r#""1/0""#).unwrap_err();
+        assert!(zero.to_string().contains("denominator cannot be zero"));
+        assert!(serde_json::from_str::<Fraction>(r#""1/2/3""#).is_err());
+        assert!(serde_json::from_str::<Fraction>("0.5").is_err());
     }
 
     #[test]
-    fn test_comparison() {
-        let half = Fraction::new(1, 2).unwrap();
-        let third = Fraction::new(1, 3).unwrap();
-        let also_half = Fraction::new(2, 4).unwrap();
+    fn test_sum_and_product() {
+        let parts = [frac(1, 2), frac(1, 3), frac(1, 6)];
+        assert_eq!(parts.iter().sum::<Fraction>(), frac(1, 1));
+        assert_eq!(parts.iter().copied().sum::<Fraction>(), frac(1, 1));
 
-        assert!(half > third);
-        assert!(third < half);
-        assert_eq!(half, also_half);
-        assert_ne!(half, third);
+        let factors = vec![frac(2, 3), frac(3, 4), frac(4, 5)];
+        assert_eq!(factors.iter().product::<Fraction>(), frac(2, 5));
+        assert_eq!(factors.into_iter().product::<Fraction>(), frac(2, 5));
+
+        let empty: [Fraction; 0] = [];
+        let sum: Fraction = empty.iter().sum();
+        assert_eq!((sum.numerator(), sum.denominator()), (0, 1));
+        let product: Fraction = empty.iter().product();
+        assert_eq!((product.numerator(), product.denominator()), (1, 1));
+
+        // Reducing as we go keeps the telescoping product small
+        let telescoping: Fraction = (1..=1000).map(|n| frac(n, n + 1)).product();
+        assert_eq!(telescoping, frac(1, 1001));
+
+        assert_eq!(checked_sum(&parts), Ok(frac(1, 1)));
+        assert_eq!(checked_sum(&[]), Ok(frac(0, 1)));
+        assert_eq!(checked_product(&[frac(2, 3), frac(3, 4)]), Ok(frac(1, 2)));
+        assert_eq!(checked_product(&[]), Ok(frac(1, 1)));
+
+        let harmonic: Vec<_> = (1..60).map(|k| frac(1, k)).collect();
+        assert_eq!(checked_sum(&harmonic), Err(FractionError::Overflow));
+        let powers = [frac(i64::MAX, 1), frac(2, 1)];
+        assert_eq!(checked_product(&powers), Err(FractionError::Overflow));
     }
 
     #[test]
-    fn test_utility_methods() {
-        let half = Fraction::new(1, 2).unwrap();
-        let neg_half = Fraction::new(-1, 2).unwrap();
-        let zero = Fraction::from_integer(0);
-        let five = Fraction::from_integer(5);
+    fn test_stern_brocot_search() {
+        // Converge on 3/7 by comparing against the target
+        let target = frac(3, 7);
+        let mut search = SternBrocotSearch::new();
+        let mut moves = String::new();
+        while search.current() != target {
+            if target < search.current() {
+                search.go_left();
+                moves.push('L');
+            } else {
+                search.go_right();
+                moves.push('R');
+            }
+        }
+        assert_eq!(moves, "LLRR");
 
-        assert_eq!(half.numerator(), 1);
-        assert_eq!(half.denominator(), 2);
-        assert_eq!(half.to_f64(), 0.5);
+        let mut search = SternBrocotSearch::default();
+        search.go_right();
+        assert_eq!(search.current(), frac(2, 1));
+        search.go_left();
+        assert_eq!(search.current(), frac(3, 2));
+        search.go_left();
+        let current = search.current();
+        assert_eq!((current.numerator(), current.denominator()), (4, 3));
+        assert_eq!(current.stern_brocot_depth(), Ok(3));
+    }
 
-        assert!(half.is_positive());
-        assert!(!half.is_negative());
-        assert!(!half.is_zero());
+    #[test]
+    fn test_integer_operators() {
+        let half = frac(1, 2);
 
-        assert!(neg_half.is_negative());
-        assert!(!neg_half.is_positive());
+        assert_eq!(half + 1, frac(3, 2));
+        assert_eq!(half - 2, frac(-3, 2));
+        assert_eq!(half * 4, frac(2, 1));
+        assert_eq!(half / 3, Ok(frac(1, 6)));
+        assert_eq!(half / 0, Err(FractionError::DivisionByZero));
 
-        assert!(zero.is_zero());
-        assert!(!zero.is_positive());
-        assert!(!zero.is_negative());
+        assert_eq!(2 * frac(1, 4), frac(1, 2));
+        assert_eq!(1 + half, frac(3, 2));
+        assert_eq!(1 - half, frac(1, 2));
+        assert_eq!(3 / frac(3, 4), Ok(frac(4, 1)));
+        assert_eq!(3 / frac(0, 1), Err(FractionError::DivisionByZero));
+    }
 
-        assert_eq!(neg_half.abs(), half);
-        assert_eq!(five.to_string(), "5");
+    #[test]
+    fn test_to_roman_uncia() {
+        let roman = |n, d| frac(n, d).to_roman_uncia();
+
+        assert_eq!(roman(1, 12).as_deref(), Some("·"));
+        assert_eq!(roman(1, 2).as_deref(), Some("S"));
+        assert_eq!(roman(1, 4).as_deref(), Some("···"));
+        assert_eq!(roman(5, 12).as_deref(), Some("·····"));
+        assert_eq!(roman(11, 12).as_deref(), Some("S·····"));
+        assert_eq!(roman(1, 24).as_deref(), Some("Σ"));
+        assert_eq!(roman(13, 24).as_deref(), Some("SΣ"));
+        assert_eq!(roman(0, 1).as_deref(), Some("N"));
+
+        assert_eq!(roman(1, 1), None);
+        assert_eq!(roman(-1, 12), None);
+        assert_eq!(roman(1, 48), None);
+        assert_eq!(roman(1, 5), None);
     }
 
     #[test]
-    fn test_conversions() {
-        let five: Fraction = 5.into();
-        assert_eq!(five, Fraction::new(5, 1).unwrap());
+    fn test_assign_operators() {
+        let mut total = Fraction::from_integer(0);
+        for n in 1..=10 {
+            total += frac(1, n);
+        }
+        assert_eq!(total, frac(7381, 2520));
+        assert_eq!((total.numerator(), total.denominator()), (7381, 2520));
 
-        let frac: Fraction = (3, 4).try_into().unwrap();
-        assert_eq!(frac, Fraction::new(3, 4).unwrap());
+        let mut value = frac(1, 2);
+        value -= frac(1, 3);
+        assert_eq!(value, frac(1, 6));
+        value *= frac(3, 2);
+        assert_eq!((value.numerator(), value.denominator()), (1, 4));
+        value += 1;
+        assert_eq!(value, frac(5, 4));
+        value -= 2;
+        assert_eq!(value, frac(-3, 4));
+        value *= 4;
+        assert_eq!(value, frac(-3, 1));
 
-        let zero_den: Result<Fraction, _> = (1, 0).try_into();
-        assert!(zero_den.is_err());
+        value.checked_div_assign(&frac(-6, 1)).unwrap();
+        assert_eq!(value, frac(1, 2));
+        assert_eq!(
+            value.checked_div_assign(&frac(0, 1)),
+            Err(FractionError::DivisionByZero)
+        );
+        assert_eq!(value, frac(1, 2));
+    }
+
+    #[test]
+    fn test_reduce_ratio() {
+        assert_eq!(reduce_ratio(12, -8), Ok((-3, 2)));
+        assert_eq!(reduce_ratio(-12, -8), Ok((3, 2)));
+        assert_eq!(reduce_ratio(0, -5), Ok((0, 1)));
+        assert_eq!(reduce_ratio(7, 3), Ok((7, 3)));
+        assert_eq!(reduce_ratio(i64::MIN, 2), Ok((i64::MIN / 2, 1)));
+        assert_eq!(reduce_ratio(1, 0), Err(FractionError::ZeroDenominator));
+        assert_eq!(reduce_ratio(i64::MIN, -1), Err(FractionError::Overflow));
+    }
+
+    #[test]
+    fn test_try_from_f64() {
+        assert_eq!(Fraction::try_from(0.5), Ok(frac(1, 2)));
+        assert_eq!(Fraction::try_from(0.25), Ok(frac(1, 4)));
+        assert_eq!(Fraction::try_from(3.0), Ok(frac(3, 1)));
+        assert_eq!(Fraction::try_from(-0.375), Ok(frac(-3, 8)));
+
+        let tenth = Fraction::try_from(0.1).unwrap();
+        assert_eq!(tenth.denominator(), 1 << 55);
+        assert_eq!(tenth.to_f64(), 0.1);
+
+        assert_eq!(Fraction::try_from(f64::NAN), Err(FractionError::NotFinite));
+        assert_eq!(
+            Fraction::try_from(f64::INFINITY),
+            Err(FractionError::NotFinite)
+        );
+        assert_eq!(Fraction::try_from(1e300), Err(FractionError::Overflow));
+        assert_eq!(Fraction::try_from(1e-300), Err(FractionError::Overflow));
+    }
+
+    #[test]
+    fn test_convergent_error() {
+        // 355/113 = [3; 7, 16]
+        let pi_approx = frac(355, 113);
+        assert_eq!(pi_approx.convergent_error(0), Ok(frac(16, 113)));
+        assert_eq!(pi_approx.convergent_error(1), Ok(frac(1, 791)));
+        assert_eq!(pi_approx.convergent_error(2), Ok(frac(0, 1)));
+        assert_eq!(
+            pi_approx.convergent_error(3),
+            Err(FractionError::InvalidArgument)
+        );
+
+        let errors: Vec<_> = (0..5)
+            .map(|k| frac(13, 8).convergent_error(k).unwrap())
+            .collect();
+        assert!(errors.windows(2).all(|pair| pair[1] < pair[0]));
+
+        assert_eq!(frac(-7, 2).convergent_error(0), Ok(frac(1, 2)));
+        assert_eq!(frac(5, 1).convergent_error(0), Ok(frac(0, 1)));
+    }
+
+    #[test]
+    fn test_approximate() {
+        assert_eq!(Fraction::approximate(0.3333333, 100), frac(1, 3));
+        assert_eq!(
+            Fraction::approximate(std::f64::consts::PI, 1000),
+            frac(355, 113)
+        );
+        assert_eq!(
+            Fraction::approximate(std::f64::consts::PI, 100),
+            frac(311, 99)
+        );
+        assert_eq!(Fraction::approximate(-2.5, 10), frac(-5, 2));
+        assert_eq!(Fraction::approximate(1.7499, 4), frac(7, 4));
+        assert_eq!(Fraction::approximate(0.0, 10), frac(0, 1));
+        assert_eq!(Fraction::approximate(0.4, 0), frac(0, 1));
+    }
+
+    #[test]
+    fn test_convert() {
+        assert_eq!(frac(3, 2).convert(1, 100), Ok(frac(150, 1)));
+        assert_eq!(frac(3, 2).convert(100, 1), Ok(frac(3, 200)));
+        assert_eq!(frac(5, 1).convert(1000, 1609), Ok(frac(1609, 200)));
+        assert_eq!(frac(1, 3).convert(-2, 6), Ok(frac(-1, 1)));
+        assert_eq!(frac(1, 3).convert(0, 6), Err(FractionError::DivisionByZero));
+        assert_eq!(
+            frac(i64::MAX, 1).convert(1, 2),
+            Err(FractionError::Overflow)
+        );
+        assert_eq!(frac(i64::MAX, 3).convert(i64::MAX, 3), Ok(frac(1, 1)));
+    }
+
+    #[test]
+    fn test_continued_fraction_round_trip() {
+        assert_eq!(frac(355, 113).to_continued_fraction(), vec![3, 7, 16]);
+        assert_eq!(frac(-7, 3).to_continued_fraction(), vec![-3, 1, 2]);
+        assert_eq!(frac(1, 2).to_continued_fraction(), vec![0, 2]);
+        assert_eq!(frac(-4, 1).to_continued_fraction(), vec![-4]);
+        assert_eq!(frac(0, 1).to_continued_fraction(), vec![0]);
+
+        for value in [
+            frac(355, 113),
+            frac(-7, 3),
+            frac(0, 1),
+            frac(13, 8),
+            frac(-1, 1000),
+            frac(i64::MAX, i64::MAX - 1),
+            frac(i64::MIN + 1, 3),
+            Fraction::new_unreduced(6, -4).unwrap(),
+        ] {
+            let coeffs = value.to_continued_fraction();
+            let back = Fraction::from_continued_fraction(&coeffs).unwrap();
+            let expected = value.reduce();
+            assert_eq!(back.numerator(), expected.numerator(), "{}", value);
+            assert_eq!(back.denominator(), expected.denominator(), "{}", value);
+        }
+    }
+
+    #[test]
+    fn test_validate_reduced_bounded() {
+        assert_eq!(validate_reduced_bounded(3, 4, 4), Ok(frac(3, 4)));
+        assert_eq!(validate_reduced_bounded(3, -4, 4), Ok(frac(-3, 4)));
+        assert_eq!(validate_reduced_bounded(0, 1, 1), Ok(frac(0, 1)));
+
+        assert_eq!(
+            validate_reduced_bounded(6, 8, 100),
+            Err(FractionError::InvalidArgument)
+        );
+        assert_eq!(
+            validate_reduced_bounded(0, 5, 100),
+            Err(FractionError::InvalidArgument)
+        );
+        assert_eq!(
+            validate_reduced_bounded(3, 5, 4),
+            Err(FractionError::InvalidArgument)
+        );
+        assert_eq!(
+            validate_reduced_bounded(1, 0, 4),
+            Err(FractionError::ZeroDenominator)
+        );
+    }
+
+    #[test]
+    fn test_to_mixed_string() {
+        assert_eq!(frac(7, 2).to_mixed_string(), "3 1/2");
+        assert_eq!(frac(-7, 2).to_mixed_string(), "-3 1/2");
+        assert_eq!(frac(4, 2).to_mixed_string(), "2");
+        assert_eq!(frac(-4, 2).to_mixed_string(), "-2");
+        assert_eq!(frac(1, 2).to_mixed_string(), "1/2");
+        assert_eq!(frac(-1, 2).to_mixed_string(), "-1/2");
+        assert_eq!(frac(0, 3).to_mixed_string(), "0");
+        assert_eq!(frac(22, 6).to_mixed_string(), "3 2/3");
+        assert_eq!(frac(7, 2).to_string(), "7/2");
+    }
+
+    #[test]
+    fn test_progress() {
+        assert_eq!(Fraction::progress(-5, 10), Ok(frac(0, 1)));
+        assert_eq!(Fraction::progress(0, 10), Ok(frac(0, 1)));
+        assert_eq!(Fraction::progress(4, 10), Ok(frac(2, 5)));
+        assert_eq!(Fraction::progress(10, 10), Ok(frac(1, 1)));
+        assert_eq!(Fraction::progress(25, 10), Ok(frac(1, 1)));
+        assert_eq!(Fraction::progress(i64::MAX, 3), Ok(frac(1, 1)));
+        assert_eq!(
+            Fraction::progress(1, 0),
+            Err(FractionError::ZeroDenominator)
+        );
+    }
+
+    #[test]
+    fn test_blend() {
+        let points = [frac(1, 2), frac(1, 3), frac(1, 6)];
+        let equal = [frac(1, 1); 3];
+        assert_eq!(blend(&points, &equal), Ok(frac(1, 3)));
+        assert_eq!(
+            blend(&points, &[frac(1, 2), frac(1, 2), frac(0, 1)]),
+            Ok(frac(5, 12))
+        );
+        assert_eq!(
+            blend(&points, &[frac(-1, 1), frac(2, 1), frac(0, 1)]),
+            Ok(frac(1, 6))
+        );
+
+        assert_eq!(
+            blend(&points, &equal[..2]),
+            Err(FractionError::InvalidArgument)
+        );
+        assert_eq!(
+            blend(&points, &[frac(1, 1), frac(-1, 1), frac(0, 1)]),
+            Err(FractionError::DivisionByZero)
+        );
+        assert_eq!(blend(&[], &[]), Err(FractionError::DivisionByZero));
+    }
+
+    #[test]
+    fn test_from_str_mixed_numbers() {
+        assert_eq!("1 1/2".parse::<Fraction>(), Ok(frac(3, 2)));
+        assert_eq!("-2 3/4".parse::<Fraction>(), Ok(frac(-11, 4)));
+        assert_eq!("−1 1/2".parse::<Fraction>(), Ok(frac(-3, 2)));
+        assert_eq!("-0 1/2".parse::<Fraction>(), Ok(frac(-1, 2)));
+        assert_eq!("  3   2/4 ".parse::<Fraction>(), Ok(frac(7, 2)));
+        assert_eq!("0 0/5".parse::<Fraction>(), Ok(frac(0, 1)));
+
+        for bad in [
+            "1 2", "1 -1/2", "1 1/-2", "1 1/−2", "1 3/2", "1 1/2/3", "1 1/2 3", "1 /2", "a 1/2",
+        ] {
+            assert_eq!(
+                bad.parse::<Fraction>(),
+                Err(FractionError::ParseError),
+                "{:?}",
+                bad
+            );
+        }
+        assert_eq!(
+            "1 1/0".parse::<Fraction>(),
+            Err(FractionError::ZeroDenominator)
+        );
+        assert_eq!(
+            format!("{} 1/2", i64::MAX).parse::<Fraction>(),
+            Err(FractionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_cmp_lexicographic() {
+        for a in -12..=12 {
+            for b in 1..=12 {
+                for c in -12..=12 {
+                    for d in 1..=12 {
+                        let (x, y) = (frac(a, b), frac(c, d));
+                        assert_eq!(x.cmp_lexicographic(&y), x.cmp(&y), "{} vs {}", x, y);
+                    }
+                }
+            }
+        }
+
+        let big = [
+            frac(i64::MAX, i64::MAX - 1),
+            frac(i64::MAX - 1, i64::MAX - 2),
+            frac(i64::MIN + 1, i64::MAX),
+            frac(i64::MIN, i64::MAX),
+            frac(1, i64::MAX),
+            frac(1, i64::MAX - 1),
+            frac(i64::MAX, 1),
+        ];
+        for x in &big {
+            for y in &big {
+                assert_eq!(x.cmp_lexicographic(y), x.safe_cmp(y), "{} vs {}", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn test_zero_and_one_constants() {
+        const HALF_OPEN: [Fraction; 2] = [Fraction::ZERO, Fraction::ONE];
+
+        assert!(Fraction::ZERO.is_zero());
+        assert_eq!(Fraction::ONE, Fraction::from_integer(1));
+        assert_eq!(HALF_OPEN[0], Fraction::from_integer(0));
+        assert_eq!(
+            (Fraction::ONE.numerator(), Fraction::ONE.denominator()),
+            (1, 1)
+        );
+
+        let mut total = Fraction::ZERO;
+        total += Fraction::ONE;
+        assert_eq!(total, Fraction::ONE);
+    }
+
+    #[test]
+    fn test_new_const() {
+        const THREE_QUARTERS: Fraction = Fraction::new_const(3, 4);
+        const NEGATIVE: Fraction = Fraction::new_const(6, -8);
+
+        assert_eq!(THREE_QUARTERS, Fraction::new(3, 4).unwrap());
+        assert_eq!((NEGATIVE.numerator(), NEGATIVE.denominator()), (-6, 8));
+
+        let runtime = Fraction::new_const(-2, -5);
+        assert_eq!((runtime.numerator(), runtime.denominator()), (2, 5));
+    }
+
+    #[test]
+    #[should_panic(expected = "denominator cannot be zero")]
+    fn test_new_const_zero_denominator() {
+        let _ = Fraction::new_const(1, 0);
+    }
+
+    #[test]
+    fn test_histogram() {
+        let values = [
+            frac(-1, 2),
+            frac(0, 1),
+            frac(1, 6),
+            frac(1, 3),
+            frac(1, 2),
+            frac(2, 3),
+            frac(5, 6),
+            frac(1, 1),
+            frac(3, 2),
+        ];
+        let edges = [frac(0, 1), frac(1, 3), frac(2, 3), frac(1, 1)];
+        assert_eq!(histogram(&values, &edges), Ok(vec![2, 2, 2]));
+        assert_eq!(histogram(&[], &edges), Ok(vec![0, 0, 0]));
+
+        let big = [frac(i64::MAX, 3), frac(i64::MAX - 1, 3)];
+        assert_eq!(
+            histogram(&big, &[frac(i64::MAX - 1, 3), frac(i64::MAX, 3)]),
+            Ok(vec![1])
+        );
+
+        assert_eq!(
+            histogram(&values, &[frac(0, 1)]),
+            Err(FractionError::InvalidArgument)
+        );
+        assert_eq!(
+            histogram(&values, &[frac(0, 1), frac(1, 1), frac(1, 1)]),
+            Err(FractionError::InvalidArgument)
+        );
+        assert_eq!(
+            histogram(&values, &[frac(1, 1), frac(0, 1)]),
+            Err(FractionError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn test_comparison_large_terms() {
+        let a = frac(i64::MAX, 2);
+        let b = frac(i64::MAX, 3);
+        assert!(a > b);
+        assert!(b < a);
+        assert_ne!(a, b);
+        assert_eq!(a.max(b), a);
+
+        let close = frac(i64::MAX, i64::MAX - 1);
+        let closer = frac(i64::MAX - 1, i64::MAX - 2);
+        assert!(close < closer);
+        assert!(frac(i64::MIN, i64::MAX) < frac(i64::MIN + 1, i64::MAX));
+        assert!(frac(1, i64::MAX) < frac(1, i64::MAX - 1));
+
+        let unreduced = Fraction::new_unreduced(i64::MAX - 1, i64::MAX - 1).unwrap();
+        assert_eq!(unreduced, Fraction::ONE);
+        assert_eq!(
+            Fraction::new_unreduced(i64::MIN, i64::MAX - 1).unwrap(),
+            frac(i64::MIN, i64::MAX - 1)
+        );
+
+        let mut sorted = vec![close, frac(-1, 2), a, closer, b];
+        sorted.sort();
+        assert_eq!(sorted, vec![frac(-1, 2), close, closer, b, a]);
+    }
+
+    #[test]
+    fn test_apportion() {
+        let votes = |counts: &[i64]| -> Vec<Fraction> {
+            counts.iter().map(|&n| Fraction::from_integer(n)).collect()
+        };
+
+        let parties = votes(&[47_000, 16_000, 15_800, 12_000, 6_100, 3_100]);
+        assert_eq!(apportion(10, &parties), Ok(vec![5, 2, 1, 1, 1, 0]));
+
+        // Exact ties go to the earlier party
+        let even = votes(&[1, 1, 1]);
+        assert_eq!(apportion(4, &even), Ok(vec![2, 1, 1]));
+        assert_eq!(apportion(0, &even), Ok(vec![0, 0, 0]));
+
+        let thirds = [Fraction::new(1, 3).unwrap(); 3];
+        assert_eq!(apportion(7, &thirds).unwrap().iter().sum::<i64>(), 7);
+
+        assert_eq!(apportion(-1, &even), Err(FractionError::InvalidArgument));
+        assert_eq!(
+            apportion(3, &votes(&[2, -1])),
+            Err(FractionError::InvalidArgument)
+        );
+        assert_eq!(
+            apportion(3, &votes(&[0, 0])),
+            Err(FractionError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_signum() {
+        let positive = frac(3, 4).signum();
+        assert_eq!((positive.numerator(), positive.denominator()), (1, 1));
+        let negative = frac(-3, 4).signum();
+        assert_eq!((negative.numerator(), negative.denominator()), (-1, 1));
+        let zero = frac(0, 5).signum();
+        assert_eq!((zero.numerator(), zero.denominator()), (0, 1));
+        assert_eq!(frac(i64::MIN, 1).signum(), frac(-1, 1));
+    }
+
+    #[test]
+    fn test_to_engineering_string() {
+        assert_eq!(frac(12345, 1).to_engineering_string(3), "12.3e3");
+        assert_eq!(frac(12345, 1).to_engineering_string(5), "12.345e3");
+        assert_eq!(frac(123_456_789, 1).to_engineering_string(4), "123.5e6");
+        assert_eq!(frac(1, 8).to_engineering_string(2), "130e-3");
+        assert_eq!(frac(-1, 8).to_engineering_string(3), "-125e-3");
+        assert_eq!(frac(1, 3).to_engineering_string(4), "333.3e-3");
+        assert_eq!(frac(999_999, 1).to_engineering_string(3), "1.00e6");
+        assert_eq!(frac(7, 2).to_engineering_string(0), "4e0");
+        assert_eq!(frac(1, 1000).to_engineering_string(1), "1e-3");
+        assert_eq!(frac(0, 1).to_engineering_string(3), "0e0");
+    }
+
+    #[test]
+    fn test_is_integer() {
+        let raw = |n, d| Fraction::new_unreduced(n, d).unwrap();
+
+        assert!(frac(4, 2).is_integer());
+        assert!(raw(4, 2).is_integer());
+        assert!(!frac(1, 2).is_integer());
+        assert!(!raw(6, 4).is_integer());
+        assert!(frac(0, 5).is_integer());
+        assert!(raw(0, 5).is_integer());
+        assert!(raw(-9, 3).is_integer());
+        assert!(frac(i64::MIN, 1).is_integer());
+    }
+
+    #[test]
+    fn test_solve_proportion() {
+        assert_eq!(
+            solve_proportion(&frac(2, 1), &frac(3, 1), &frac(4, 1)),
+            Ok(frac(6, 1))
+        );
+        assert_eq!(
+            solve_proportion(&frac(1, 2), &frac(3, 4), &frac(5, 6)),
+            Ok(frac(5, 4))
+        );
+        assert_eq!(
+            solve_proportion(&frac(-2, 1), &frac(3, 1), &frac(4, 1)),
+            Ok(frac(-6, 1))
+        );
+
+        let zero = frac(0, 1);
+        for (a, b, c) in [
+            (zero, frac(3, 1), frac(4, 1)),
+            (frac(2, 1), zero, frac(4, 1)),
+            (frac(2, 1), frac(3, 1), zero),
+        ] {
+            assert_eq!(
+                solve_proportion(&a, &b, &c),
+                Err(FractionError::DivisionByZero)
+            );
+        }
+        assert_eq!(
+            solve_proportion(&frac(1, 2), &frac(i64::MAX, 1), &frac(1, 1)),
+            Err(FractionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_recip() {
+        assert_eq!(frac(3, 4).recip(), frac(4, 3));
+        assert_eq!(frac(-2, 1).recip(), frac(-1, 2));
+        assert_eq!(frac(5, 7).recip().recip(), frac(5, 7));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot take the reciprocal of a zero fraction")]
+    fn test_recip_zero() {
+        let _ = Fraction::ZERO.recip();
+    }
+
+    #[test]
+    fn test_clamp_min_max() {
+        let (low, high) = (frac(1, 4), frac(3, 4));
+
+        assert_eq!(frac(-1, 2).clamp(low, high), low);
+        assert_eq!(frac(1, 2).clamp(low, high), frac(1, 2));
+        assert_eq!(frac(3, 4).clamp(low, high), high);
+        assert_eq!(frac(5, 4).clamp(low, high), high);
+
+        assert_eq!(frac(1, 3).min(frac(1, 2)), frac(1, 3));
+        assert_eq!(frac(1, 3).max(frac(1, 2)), frac(1, 2));
+        assert_eq!(frac(-1, 3).min(frac(-1, 2)), frac(-1, 2));
+
+        // Equal values follow `Ord`: `min` keeps `self`, `max` takes `other`
+        let raw = Fraction::new_unreduced(2, 4).unwrap();
+        assert_eq!(frac(1, 2).min(raw).denominator(), 2);
+        assert_eq!(frac(1, 2).max(raw).denominator(), 4);
+
+        let big = frac(i64::MAX, 2);
+        assert_eq!(
+            big.clamp(frac(i64::MAX, 4), frac(i64::MAX, 3)),
+            frac(i64::MAX, 3)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "clamp requires low <= high")]
+    #[cfg(debug_assertions)]
+    fn test_clamp_inverted_bounds() {
+        let _ = Fraction::ZERO.clamp(Fraction::ONE, Fraction::ZERO);
     }
 }